@@ -1,8 +1,9 @@
 use bevy::prelude::*;
 use press_here::{
-    Add, And, AppExt, AxisBinding, AxisBindingBuilder, AxisVisualizer, Clamp, Deadzone, Divide,
-    Invert, MouseWheel, MouseY, Multiply, Normalize, Not, Pair, RateLimit, Remap, Smooth, Subtract,
-    Transformation, Trigger, TriggerBinding, WithCurve, WithTriggerBinding,
+    Add, And, AppExt, AxisBinding, AxisBindingBuilder, AxisVisualizer, Chord, Clamp, Cooldown,
+    Deadzone, Debounce, Divide, Hold, Invert, LongPress, ModifierKey, MouseWheel, MouseY, MultiTap,
+    Multiply, Normalize, Not, Pair, RateLimit, Remap, Repeat, Sequence, Smooth, Subtract, Tap,
+    Toggle, Transformation, Trigger, TriggerBinding, WithCurve, WithTriggerBinding,
 };
 use std::time::Duration;
 
@@ -86,8 +87,22 @@ fn main() {
         .add_trigger::<TupleTrigger>((KeyCode::KeyW, GamepadButton::South)) // Tuple of TriggerBindings. Active if any binding is active.
         .add_trigger::<VecTrigger>(vec![KeyCode::KeyW, KeyCode::ArrowUp]) // Vec of TriggerBindings. Active if any binding is active.
         .add_trigger::<AndTrigger>(And(KeyCode::KeyW, GamepadButton::South)) // Combinator that is only active if both bindings are active.
+        .add_trigger::<SequenceTrigger>(Sequence::new().then(KeyCode::KeyS).then(KeyCode::KeyJ)) // Combinator that is active when the bindings are pressed in order.
+        .add_trigger::<ChordTrigger>(Chord::new([ModifierKey::Control], KeyCode::KeyS)) // Keyboard shortcut that requires the modifiers to be held first, on either side.
         // Trigger modifiers
         .add_trigger::<NotTrigger>(Not(KeyCode::KeyW)) // Modifier that inverts the trigger state.
+        .add_trigger::<HoldTrigger>(Hold::new(KeyCode::KeyE, Duration::from_secs(1))) // Modifier that is only active after the binding has been held for the given duration.
+        .add_trigger::<TapTrigger>(Tap::new(KeyCode::KeyE, Duration::from_millis(200))) // Modifier that is active for a frame when the binding is released quickly.
+        .add_trigger::<LongPressTrigger>(LongPress::new(KeyCode::KeyE, Duration::from_secs(1))) // Modifier that is active for a frame when the binding is released after being held.
+        .add_trigger::<MultiTapTrigger>(MultiTap::new(KeyCode::KeyD, 2, Duration::from_millis(300))) // Modifier that is active when the binding is pressed the given number of times in quick succession.
+        .add_trigger::<ToggleTrigger>(Toggle::new(KeyCode::KeyC)) // Modifier that flips between active and inactive each time the binding is pressed.
+        .add_trigger::<CooldownTrigger>(Cooldown::new(KeyCode::KeyF, Duration::from_secs(1))) // Modifier that ignores presses for the given duration after it was active.
+        .add_trigger::<DebounceTrigger>(Debounce::new(MouseButton::Left, Duration::from_millis(50))) // Modifier that ignores changes for the given duration after the last change.
+        .add_trigger::<RepeatTrigger>(Repeat::new(
+            KeyCode::ArrowDown,
+            Duration::from_millis(400),
+            Duration::from_millis(100),
+        )) // Modifier that is just pressed again at a fixed rate while the binding is held.
         .add_systems(
             Update,
             (
@@ -143,8 +158,18 @@ struct BoxedTrigger;
 struct TupleTrigger;
 struct VecTrigger;
 struct AndTrigger;
+struct SequenceTrigger;
+struct ChordTrigger;
 
 struct NotTrigger;
+struct HoldTrigger;
+struct TapTrigger;
+struct LongPressTrigger;
+struct MultiTapTrigger;
+struct ToggleTrigger;
+struct CooldownTrigger;
+struct DebounceTrigger;
+struct RepeatTrigger;
 
 #[allow(clippy::too_many_arguments)]
 fn visualize_basic(
@@ -1,38 +1,49 @@
 use bevy::prelude::*;
-use press_here::{AppExt, Axis, AxisBinding, Pair, Trigger};
+use press_here::{
+    AppExt, BindingCapture, BindingCaptured, CaptureOutcome, CaptureRequest, Trigger,
+};
+use std::time::Duration;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_trigger::<ChangeBinding>(KeyCode::Space)
-        .add_axis::<SomeAxis>((Pair(KeyCode::KeyA, KeyCode::KeyD), GamepadAxis::LeftStickX))
-        .add_systems(Update, change_axis_binding)
-        .add_systems(Update, print_axis)
+        .add_trigger::<ChangeBinding>(KeyCode::F1)
+        .add_trigger::<Jump>((KeyCode::Space, GamepadButton::South))
+        .add_systems(Update, (start_capture, finish_capture, print_jump))
         .run();
 }
 
 pub struct ChangeBinding;
-pub struct SomeAxis;
+pub struct Jump;
 
-fn change_axis_binding(trigger: Res<Trigger<ChangeBinding>>, mut axis: ResMut<Axis<SomeAxis>>) {
-    if trigger.just_pressed() {
-        let current = axis.binding();
-        let mut all = current.all_axes();
+fn start_capture(trigger: Res<Trigger<ChangeBinding>>, mut capture: ResMut<BindingCapture>) {
+    if trigger.just_pressed() && !capture.is_capturing() {
+        println!("Press any key or button to bind it to jump. Press Escape to cancel.");
 
-        let first = &all[0];
+        // Replace the first slot of the jump binding (the space key), keeping the gamepad button.
+        capture.start(
+            CaptureRequest::trigger::<Jump>(0)
+                .exclude(KeyCode::F1)
+                .with_timeout(Duration::from_secs(5)),
+        );
+    }
+}
 
-        if let Some(pair) = first.as_any().downcast_ref::<Pair<KeyCode, KeyCode>>() {
-            if pair.0 == KeyCode::KeyA {
-                all[0] = Box::new(Pair(KeyCode::ArrowLeft, KeyCode::ArrowRight));
-            } else {
-                all[0] = Box::new(Pair(KeyCode::KeyA, KeyCode::KeyD));
+fn finish_capture(mut captured: MessageReader<BindingCaptured>, mut jump: ResMut<Trigger<Jump>>) {
+    for captured in captured.read() {
+        match captured.outcome {
+            CaptureOutcome::Captured(input) => {
+                captured.apply_to_trigger(&mut jump);
+                println!("Jump is now bound to {:?}", input.source);
             }
+            CaptureOutcome::Cancelled => println!("Rebinding cancelled"),
+            CaptureOutcome::TimedOut => println!("Rebinding timed out"),
         }
-
-        axis.set_binding(all);
     }
 }
 
-fn print_axis(axis: Res<Axis<SomeAxis>>) {
-    println!("Axis value: {}", axis.value());
+fn print_jump(jump: Res<Trigger<Jump>>) {
+    if jump.just_pressed() {
+        println!("Jump!");
+    }
 }
@@ -1,16 +1,296 @@
 use crate::{
     axis::{Axis, AxisBinding},
-    inputs::{Inputs, InputsSystemParam},
+    axis2::{Axis2, DualAxisBinding},
+    capture::{BindingCapture, BindingCaptured, update_binding_capture},
+    clock::{InputClock, InputClocks, update_custom_clock, update_input_clocks},
+    conflicts::warn_binding_conflicts,
+    consume::consume_inputs,
+    context::{InputContext, InputContexts, disable_context, enable_context},
+    events::{
+        Axis2Changed, AxisChanged, TriggerPressed, TriggerReleased, emit_axis_events,
+        emit_axis2_events, emit_trigger_events,
+    },
+    gamepad::{LastUsedGamepad, update_last_used_gamepad},
+    inputs::InputsSystemParam,
+    latch::{LatchedInputs, consume_latched_inputs, latch_inputs},
+    registry::ActionRegistry,
     trigger::{Trigger, TriggerBinding},
 };
 use bevy::{
-    app::{App, PreUpdate},
-    ecs::system::ResMut,
+    app::{App, Plugin, PostStartup, PreUpdate},
+    ecs::{
+        resource::Resource,
+        schedule::{InternedScheduleLabel, IntoScheduleConfigs, ScheduleLabel, SystemSet},
+        system::{Query, Res, ResMut, ScheduleSystem},
+    },
+    input::InputSystems,
+    state::state::{OnEnter, OnExit, States},
 };
+#[cfg(feature = "serialize")]
+use {
+    crate::settings::{BindingsConfig, BindingsFile},
+    bevy::log::warn,
+    std::path::PathBuf,
+};
+
+/// The system sets that axes and triggers are updated in. They run in order, after Bevy's [InputSystems], in the
+/// schedule configured with [PressHerePlugin].
+///
+/// # Examples
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use press_here::PressHereSystems;
+/// App::new().add_systems(PreUpdate, read_inputs_early.after(PressHereSystems::Consume));
+///
+/// fn read_inputs_early() {}
+/// ```
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PressHereSystems {
+    /// Prepares state shared by all actions, e.g. [LastUsedGamepad].
+    Prepare,
+    /// Updates all axes and triggers.
+    Update,
+    /// Suppresses actions whose inputs were consumed by a higher-priority action, see
+    /// [PressHerePlugin::with_input_consumption], and clears the latched inputs, see
+    /// [PressHerePlugin::with_input_latching]. Order systems that read actions after this set.
+    Consume,
+    /// Fires events for changed actions. See [AppExt::add_trigger_events].
+    Events,
+}
+
+/// Sets up the resources and systems shared by all axes and triggers. It is added automatically with default settings
+/// by the [AppExt] methods. To configure it, add it before any axes or triggers.
+///
+/// # Examples
+/// Updating actions in the fixed timestep:
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, PressHerePlugin};
+/// App::new()
+///     .add_plugins(PressHerePlugin::default().with_schedule(FixedPreUpdate))
+///     .add_trigger::<Jump>(KeyCode::Space);
+///
+/// # struct Jump;
+/// ```
+pub struct PressHerePlugin {
+    schedule: InternedScheduleLabel,
+    latching: bool,
+    consumption: bool,
+    conflict_warnings: bool,
+    clock: InputClock,
+}
+
+impl PressHerePlugin {
+    /// Sets the schedule that axes and triggers are updated in. Defaults to [PreUpdate].
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Latches button presses and releases every frame until the actions are next updated. Use this when actions are
+    /// updated in a schedule that doesn't run exactly once per frame, such as `FixedPreUpdate`, so that a press is
+    /// neither missed on frames without a fixed step nor repeated on frames with several.
+    ///
+    /// Mouse motion and wheel messages are read since the last update in any case, so axis deltas are summed across
+    /// frames.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, PressHerePlugin, Trigger};
+    /// App::new()
+    ///     .add_plugins(
+    ///         PressHerePlugin::default()
+    ///             .with_schedule(FixedPreUpdate)
+    ///             .with_input_latching(),
+    ///     )
+    ///     .add_trigger::<Jump>(KeyCode::Space)
+    ///     .add_systems(FixedUpdate, jump);
+    ///
+    /// fn jump(jump: Res<Trigger<Jump>>) {
+    ///     // Seen exactly once, no matter how many fixed steps run per frame.
+    ///     if jump.just_pressed() {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// # struct Jump;
+    /// ```
+    pub fn with_input_latching(mut self) -> Self {
+        self.latching = true;
+        self
+    }
+
+    /// Lets actions consume their inputs, so that one physical input only drives the highest-priority action. After all
+    /// actions are updated, an active action is suppressed if another active action shares one of its held inputs and
+    /// either has a higher priority, or the same priority and a strict superset of the held inputs. So Ctrl+S suppresses
+    /// a plain S action, and an overlay's `Confirm` with a higher priority suppresses `Jump` on the same key.
+    ///
+    /// Only actions added as resources with [AppExt] take part. Actions added as components, e.g. with
+    /// [AppExt::add_trigger_component], neither consume inputs nor are suppressed. Set priorities with e.g. [Trigger::with_priority] or
+    /// [AppExt::set_action_priority].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, Chord, ModifierKey, PressHerePlugin, Trigger, testing::MockAppExt};
+    /// let mut app = App::new();
+    /// app.init_mock_inputs()
+    ///     .add_plugins(PressHerePlugin::default().with_input_consumption())
+    ///     .add_trigger::<Jump>(KeyCode::Space)
+    ///     .add_trigger::<Confirm>(KeyCode::Space)
+    ///     .set_action_priority::<Confirm>(1)
+    ///     .add_trigger::<Save>(Chord::new([ModifierKey::Control], KeyCode::KeyS))
+    ///     .add_trigger::<MoveBack>(KeyCode::KeyS);
+    ///
+    /// app.press(KeyCode::Space).update();
+    /// assert!(app.world().resource::<Trigger<Confirm>>().just_pressed());
+    /// assert!(!app.world().resource::<Trigger<Jump>>().pressed());
+    ///
+    /// // Jump is pressed once Confirm no longer claims the key.
+    /// app.world_mut().resource_mut::<Trigger<Confirm>>().set_binding(KeyCode::Enter);
+    /// app.update();
+    /// assert!(app.world().resource::<Trigger<Jump>>().just_pressed());
+    ///
+    /// app.press(KeyCode::ControlLeft).update();
+    /// app.press(KeyCode::KeyS).update();
+    /// assert!(app.world().resource::<Trigger<Save>>().just_pressed());
+    /// assert!(!app.world().resource::<Trigger<MoveBack>>().pressed());
+    ///
+    /// # struct Jump;
+    /// # struct Confirm;
+    /// # struct Save;
+    /// # struct MoveBack;
+    /// ```
+    pub fn with_input_consumption(mut self) -> Self {
+        self.consumption = true;
+        self
+    }
+
+    /// Logs a warning for every [BindingConflict](crate::BindingConflict) between the actions added at startup.
+    pub fn with_conflict_warnings(mut self) -> Self {
+        self.conflict_warnings = true;
+        self
+    }
+
+    /// Sets the clock that time-based bindings read from. Defaults to [InputClock::Real]. Individual actions can
+    /// override it, e.g. with [Axis::with_clock].
+    pub fn with_clock(mut self, clock: InputClock) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Default for PressHerePlugin {
+    fn default() -> Self {
+        Self {
+            schedule: PreUpdate.intern(),
+            latching: false,
+            consumption: false,
+            conflict_warnings: false,
+            clock: InputClock::Real,
+        }
+    }
+}
+
+/// The schedule configured with [PressHerePlugin].
+#[derive(Resource)]
+struct UpdateSchedule(InternedScheduleLabel);
+
+impl Plugin for PressHerePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(UpdateSchedule(self.schedule))
+            .insert_resource(InputClocks::new(self.clock))
+            .init_resource::<LastUsedGamepad>()
+            .init_resource::<ActionRegistry>()
+            .init_resource::<InputContexts>()
+            .init_resource::<BindingCapture>()
+            .add_message::<BindingCaptured>()
+            .configure_sets(
+                self.schedule,
+                (
+                    PressHereSystems::Prepare,
+                    PressHereSystems::Update,
+                    PressHereSystems::Consume,
+                    PressHereSystems::Events,
+                )
+                    .chain()
+                    .after(InputSystems),
+            )
+            .add_systems(
+                self.schedule,
+                (update_last_used_gamepad, update_input_clocks).in_set(PressHereSystems::Prepare),
+            )
+            .add_systems(PreUpdate, update_binding_capture.after(InputSystems));
+
+        if self.latching {
+            app.init_resource::<LatchedInputs>()
+                .add_systems(
+                    PreUpdate,
+                    latch_inputs
+                        .after(InputSystems)
+                        .before(PressHereSystems::Prepare),
+                )
+                .add_systems(
+                    self.schedule,
+                    consume_latched_inputs.in_set(PressHereSystems::Consume),
+                );
+        }
+
+        if self.conflict_warnings {
+            app.add_systems(PostStartup, warn_binding_conflicts);
+        }
+
+        if self.consumption {
+            let consume = consume_inputs.in_set(PressHereSystems::Consume);
+
+            // The latched edges must still be there when the claims are collected.
+            if self.latching {
+                app.add_systems(self.schedule, consume.before(consume_latched_inputs));
+            } else {
+                app.add_systems(self.schedule, consume);
+            }
+        }
+    }
+}
+
+fn ensure_plugin(app: &mut App) -> &mut App {
+    if !app.is_plugin_added::<PressHerePlugin>() {
+        app.add_plugins(PressHerePlugin::default());
+    }
+    app
+}
+
+/// Adds systems to the configured schedule in the given set.
+fn add_systems<M>(
+    app: &mut App,
+    set: PressHereSystems,
+    systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+) -> &mut App {
+    let schedule = ensure_plugin(app).world().resource::<UpdateSchedule>().0;
+    app.add_systems(schedule, systems.in_set(set))
+}
 
 pub trait AppExt {
     fn add_trigger<T: Send + Sync + 'static>(&mut self, binding: impl TriggerBinding) -> &mut Self;
     fn add_axis<A: Send + Sync + 'static>(&mut self, binding: impl AxisBinding) -> &mut Self;
+    fn add_axis2<A: Send + Sync + 'static>(&mut self, binding: impl DualAxisBinding) -> &mut Self;
+    fn add_trigger_component<T: Send + Sync + 'static>(&mut self) -> &mut Self;
+    fn add_axis_component<A: Send + Sync + 'static>(&mut self) -> &mut Self;
+    fn add_axis2_component<A: Send + Sync + 'static>(&mut self) -> &mut Self;
+    fn add_trigger_events<T: Send + Sync + 'static>(&mut self) -> &mut Self;
+    fn add_axis_events<A: Send + Sync + 'static>(&mut self) -> &mut Self;
+    fn add_axis2_events<A: Send + Sync + 'static>(&mut self) -> &mut Self;
+    fn add_input_clock<C: Default + Send + Sync + 'static>(&mut self) -> &mut Self;
+    fn set_action_context<A: Send + Sync + 'static>(&mut self, context: InputContext) -> &mut Self;
+    fn set_action_priority<A: Send + Sync + 'static>(&mut self, priority: i32) -> &mut Self;
+    fn enable_input_context_in_state<S: States>(
+        &mut self,
+        context: InputContext,
+        state: S,
+    ) -> &mut Self;
+    #[cfg(feature = "serialize")]
+    fn load_bindings(&mut self, path: impl Into<PathBuf>) -> &mut Self;
 }
 
 impl AppExt for App {
@@ -31,8 +311,12 @@ impl AppExt for App {
     fn add_trigger<T: Send + Sync + 'static>(&mut self, binding: impl TriggerBinding) -> &mut Self {
         let trigger = Trigger::<T>::new(binding);
 
-        self.insert_resource(trigger)
-            .add_systems(PreUpdate, update_trigger::<T>)
+        ensure_plugin(self)
+            .world_mut()
+            .resource_mut::<ActionRegistry>()
+            .register_trigger::<T>();
+
+        add_systems(self, PressHereSystems::Update, update_trigger::<T>).insert_resource(trigger)
     }
 
     /// Adds an axis to the app with the given binding. This will insert the axis as a resource and set up the necessary
@@ -53,49 +337,374 @@ impl AppExt for App {
     fn add_axis<A: Send + Sync + 'static>(&mut self, binding: impl AxisBinding) -> &mut Self {
         let axis = Axis::<A>::new(binding);
 
-        self.insert_resource(axis)
-            .add_systems(PreUpdate, update_axis::<A>)
+        ensure_plugin(self)
+            .world_mut()
+            .resource_mut::<ActionRegistry>()
+            .register_axis::<A>();
+
+        add_systems(self, PressHereSystems::Update, update_axis::<A>).insert_resource(axis)
+    }
+
+    /// Adds a two-dimensional axis to the app with the given binding. This will insert the axis as a resource and set
+    /// up the necessary systems to update it.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, DualAxisBindingBuilder, GamepadStick, VirtualDpad};
+    /// App::new()
+    ///     .add_axis2::<Move>((
+    ///         VirtualDpad::wasd().dual_normalize(),
+    ///         GamepadStick::Left.radial_deadzone(0.1),
+    ///     ));
+    ///
+    /// # struct Move;
+    /// ```
+    fn add_axis2<A: Send + Sync + 'static>(&mut self, binding: impl DualAxisBinding) -> &mut Self {
+        ensure_plugin(self)
+            .world_mut()
+            .resource_mut::<ActionRegistry>()
+            .register_axis2::<A>();
+
+        add_systems(self, PressHereSystems::Update, update_axis2::<A>)
+            .insert_resource(Axis2::<A>::new(binding))
+    }
+
+    /// Sets up the necessary systems to update every [`Trigger<T>`] component. Unlike
+    /// [`add_trigger`](AppExt::add_trigger), no resource is inserted, so each entity can carry its own binding and state.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, Trigger};
+    /// App::new()
+    ///     .add_trigger_component::<Jump>()
+    ///     .add_systems(Startup, spawn_players);
+    ///
+    /// fn spawn_players(mut commands: Commands) {
+    ///     commands.spawn(Trigger::<Jump>::new(KeyCode::Space));
+    ///     commands.spawn(Trigger::<Jump>::new(KeyCode::Enter));
+    /// }
+    ///
+    /// fn jump(players: Query<&Trigger<Jump>>) {
+    ///     for trigger in &players {
+    ///         if trigger.just_pressed() {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # struct Jump;
+    /// ```
+    fn add_trigger_component<T: Send + Sync + 'static>(&mut self) -> &mut Self {
+        add_systems(
+            self,
+            PressHereSystems::Update,
+            update_trigger_components::<T>,
+        )
+    }
+
+    /// Sets up the necessary systems to update every [`Axis<A>`] component. Unlike [`add_axis`](AppExt::add_axis), no
+    /// resource is inserted, so each entity can carry its own binding and value.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, Axis, Pair};
+    /// App::new()
+    ///     .add_axis_component::<Walk>()
+    ///     .add_systems(Startup, spawn_players);
+    ///
+    /// fn spawn_players(mut commands: Commands) {
+    ///     commands.spawn(Axis::<Walk>::new(Pair(KeyCode::KeyA, KeyCode::KeyD)));
+    ///     commands.spawn(Axis::<Walk>::new(Pair(KeyCode::ArrowLeft, KeyCode::ArrowRight)));
+    /// }
+    ///
+    /// # struct Walk;
+    /// ```
+    fn add_axis_component<A: Send + Sync + 'static>(&mut self) -> &mut Self {
+        add_systems(self, PressHereSystems::Update, update_axis_components::<A>)
+    }
+
+    /// Sets up the necessary systems to update every [`Axis2<A>`] component. Unlike [`add_axis2`](AppExt::add_axis2),
+    /// no resource is inserted, so each entity can carry its own binding and value.
+    fn add_axis2_component<A: Send + Sync + 'static>(&mut self) -> &mut Self {
+        add_systems(self, PressHereSystems::Update, update_axis2_components::<A>)
+    }
+
+    /// Fires [TriggerPressed] and [TriggerReleased] for the [`Trigger<T>`] resource and all [`Trigger<T>`]
+    /// components. The events are both triggered for observers and written as messages.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, TriggerPressed, TriggerReleased, testing::MockAppExt};
+    /// let mut app = App::new();
+    /// app.init_mock_inputs()
+    ///     .init_resource::<Jumps>()
+    ///     .add_trigger::<Jump>(KeyCode::Space)
+    ///     .add_trigger_events::<Jump>()
+    ///     .add_observer(|_: On<TriggerPressed<Jump>>, mut jumps: ResMut<Jumps>| jumps.0 += 1);
+    ///
+    /// app.press(KeyCode::Space).update();
+    /// app.update();
+    /// assert_eq!(app.world().resource::<Jumps>().0, 1);
+    ///
+    /// app.release(KeyCode::Space).update();
+    /// let released = app.world().resource::<Messages<TriggerReleased<Jump>>>();
+    /// assert_eq!(released.len(), 1);
+    ///
+    /// # struct Jump;
+    /// #[derive(Resource, Default)]
+    /// struct Jumps(u32);
+    /// ```
+    fn add_trigger_events<T: Send + Sync + 'static>(&mut self) -> &mut Self {
+        add_systems(self, PressHereSystems::Events, emit_trigger_events::<T>)
+            .add_message::<TriggerPressed<T>>()
+            .add_message::<TriggerReleased<T>>()
+    }
+
+    /// Fires [AxisChanged] whenever the value of the [`Axis<A>`] resource or an [`Axis<A>`] component changes. The
+    /// events are both triggered for observers and written as messages.
+    fn add_axis_events<A: Send + Sync + 'static>(&mut self) -> &mut Self {
+        add_systems(self, PressHereSystems::Events, emit_axis_events::<A>)
+            .add_message::<AxisChanged<A>>()
+    }
+
+    /// Fires [Axis2Changed] whenever the value of the [`Axis2<A>`] resource or an [`Axis2<A>`] component changes. The
+    /// events are both triggered for observers and written as messages.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, Axis2Changed, VirtualDpad, testing::MockAppExt};
+    /// let mut app = App::new();
+    /// app.init_mock_inputs()
+    ///     .add_axis2::<Move>(VirtualDpad::wasd())
+    ///     .add_axis2_events::<Move>();
+    ///
+    /// app.press(KeyCode::KeyW).update();
+    /// let changes = app.world().resource::<Messages<Axis2Changed<Move>>>();
+    /// let change = changes.iter_current_update_messages().next().unwrap();
+    /// assert_eq!((change.previous, change.value), (Vec2::ZERO, Vec2::Y));
+    ///
+    /// # struct Move;
+    /// ```
+    fn add_axis2_events<A: Send + Sync + 'static>(&mut self) -> &mut Self {
+        add_systems(self, PressHereSystems::Events, emit_axis2_events::<A>)
+            .add_message::<Axis2Changed<A>>()
+    }
+
+    /// Makes the custom `Time<C>` clock available to actions as [`InputClock::custom::<C>()`](InputClock::custom).
+    /// The `Time<C>` resource must be inserted and advanced by the app.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, AxisBindingBuilder, InputClock, PressHerePlugin};
+    /// #[derive(Default)]
+    /// struct Replay;
+    ///
+    /// App::new()
+    ///     .add_plugins(PressHerePlugin::default().with_clock(InputClock::custom::<Replay>()))
+    ///     .add_input_clock::<Replay>()
+    ///     .add_axis::<Look>(GamepadAxis::RightStickX.smooth(0.1));
+    ///
+    /// # struct Look;
+    /// ```
+    fn add_input_clock<C: Default + Send + Sync + 'static>(&mut self) -> &mut Self {
+        add_systems(
+            self,
+            PressHereSystems::Prepare,
+            update_custom_clock::<C>.after(update_input_clocks),
+        )
+    }
+
+    /// Assigns the [`Trigger<A>`], [`Axis<A>`], and [`Axis2<A>`] resources to the given context, so they're only
+    /// evaluated while it's active. Must be called after the actions are added. See [InputContexts].
+    fn set_action_context<A: Send + Sync + 'static>(&mut self, context: InputContext) -> &mut Self {
+        let world = self.world_mut();
+        if let Some(mut trigger) = world.get_resource_mut::<Trigger<A>>() {
+            trigger.context = Some(context);
+        }
+        if let Some(mut axis) = world.get_resource_mut::<Axis<A>>() {
+            axis.context = Some(context);
+        }
+        if let Some(mut axis) = world.get_resource_mut::<Axis2<A>>() {
+            axis.context = Some(context);
+        }
+        self
+    }
+
+    /// Sets the priority of the [`Trigger<A>`], [`Axis<A>`], and [`Axis2<A>`] resources. Must be called after the
+    /// actions are added. See [PressHerePlugin::with_input_consumption].
+    fn set_action_priority<A: Send + Sync + 'static>(&mut self, priority: i32) -> &mut Self {
+        let world = self.world_mut();
+        if let Some(mut trigger) = world.get_resource_mut::<Trigger<A>>() {
+            trigger.priority = priority;
+        }
+        if let Some(mut axis) = world.get_resource_mut::<Axis<A>>() {
+            axis.priority = priority;
+        }
+        if let Some(mut axis) = world.get_resource_mut::<Axis2<A>>() {
+            axis.priority = priority;
+        }
+        self
+    }
+
+    /// Enables the context while the app is in the given state, see [InputContexts::enable].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, InputContext, Pair};
+    /// #[derive(States, Clone, Debug, Default, PartialEq, Eq, Hash)]
+    /// enum GameState {
+    ///     #[default]
+    ///     Playing,
+    ///     Paused,
+    /// }
+    ///
+    /// App::new()
+    ///     .init_state::<GameState>()
+    ///     .add_axis::<Walk>(Pair(KeyCode::KeyA, KeyCode::KeyD))
+    ///     .set_action_context::<Walk>(InputContext::of::<Gameplay>())
+    ///     .enable_input_context_in_state(InputContext::of::<Gameplay>(), GameState::Playing);
+    ///
+    /// # struct Walk;
+    /// # struct Gameplay;
+    /// ```
+    fn enable_input_context_in_state<S: States>(
+        &mut self,
+        context: InputContext,
+        state: S,
+    ) -> &mut Self {
+        ensure_plugin(self)
+            .add_systems(OnEnter(state.clone()), enable_context(context))
+            .add_systems(OnExit(state), disable_context(context))
+    }
+
+    /// Loads axis and trigger bindings from the RON settings file at the given path, replacing the bindings of all
+    /// axes and triggers added so far. Actions are matched by their short type name. If the file doesn't exist, the
+    /// default bindings are kept.
+    ///
+    /// The path is stored in the [BindingsFile](crate::BindingsFile) resource, so that the bindings can be written back
+    /// with the [SaveBindings](crate::SaveBindings) command, e.g. after the player rebinds keys.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, Pair};
+    /// App::new()
+    ///     .add_trigger::<Jump>(KeyCode::Space)
+    ///     .add_axis::<Walk>(Pair(KeyCode::KeyA, KeyCode::KeyD))
+    ///     // Must be called after the actions are added.
+    ///     .load_bindings("settings/bindings.ron");
+    ///
+    /// # struct Jump;
+    /// # struct Walk;
+    /// ```
+    #[cfg(feature = "serialize")]
+    fn load_bindings(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        let path = path.into();
+
+        if path.exists() {
+            match BindingsConfig::load(&path) {
+                Ok(config) => config.apply(ensure_plugin(self).world_mut()),
+                Err(error) => warn!("Failed to load bindings from {}: {error}", path.display()),
+            }
+        }
+
+        self.insert_resource(BindingsFile(path))
     }
 }
 
 fn update_trigger<T: Send + Sync + 'static>(
     mut trigger: ResMut<Trigger<T>>,
+    contexts: Res<InputContexts>,
     mut raw_inputs: InputsSystemParam,
 ) {
-    let gamepads = raw_inputs.gamepads.iter().collect::<Vec<_>>();
-    let mouse_motion = raw_inputs.mouse_motion.read().collect::<Vec<_>>();
-    let mouse_wheel = raw_inputs.mouse_wheel.read().collect::<Vec<_>>();
-
-    let inputs = Inputs {
-        keycodes: &raw_inputs.keycodes,
-        mouse_buttons: &raw_inputs.mouse_buttons,
-        mouse_motion: mouse_motion.as_slice(),
-        mouse_wheel: mouse_wheel.as_slice(),
-        gamepads: gamepads.as_slice(),
-        time: &raw_inputs.time,
-    };
-
-    trigger.pressed = trigger.binding.pressed(&inputs);
-    trigger.just_pressed = trigger.binding.just_pressed(&inputs);
-    trigger.just_released = trigger.binding.just_released(&inputs);
+    raw_inputs.with_inputs(|inputs| {
+        if contexts.allows(trigger.context) {
+            trigger.update(inputs);
+        } else {
+            trigger.deactivate();
+        }
+    });
 }
 
 fn update_axis<A: Send + Sync + 'static>(
     mut axis: ResMut<Axis<A>>,
+    contexts: Res<InputContexts>,
+    mut raw_inputs: InputsSystemParam,
+) {
+    raw_inputs.with_inputs(|inputs| {
+        if contexts.allows(axis.context) {
+            axis.update(inputs);
+        } else {
+            axis.deactivate();
+        }
+    });
+}
+
+fn update_axis2<A: Send + Sync + 'static>(
+    mut axis: ResMut<Axis2<A>>,
+    contexts: Res<InputContexts>,
+    mut raw_inputs: InputsSystemParam,
+) {
+    raw_inputs.with_inputs(|inputs| {
+        if contexts.allows(axis.context) {
+            axis.update(inputs);
+        } else {
+            axis.deactivate();
+        }
+    });
+}
+
+fn update_trigger_components<T: Send + Sync + 'static>(
+    mut triggers: Query<&mut Trigger<T>>,
+    contexts: Res<InputContexts>,
+    mut raw_inputs: InputsSystemParam,
+) {
+    raw_inputs.with_inputs(|inputs| {
+        for mut trigger in &mut triggers {
+            if contexts.allows(trigger.context) {
+                trigger.update(inputs);
+            } else {
+                trigger.deactivate();
+            }
+        }
+    });
+}
+
+fn update_axis_components<A: Send + Sync + 'static>(
+    mut axes: Query<&mut Axis<A>>,
+    contexts: Res<InputContexts>,
+    mut raw_inputs: InputsSystemParam,
+) {
+    raw_inputs.with_inputs(|inputs| {
+        for mut axis in &mut axes {
+            if contexts.allows(axis.context) {
+                axis.update(inputs);
+            } else {
+                axis.deactivate();
+            }
+        }
+    });
+}
+
+fn update_axis2_components<A: Send + Sync + 'static>(
+    mut axes: Query<&mut Axis2<A>>,
+    contexts: Res<InputContexts>,
     mut raw_inputs: InputsSystemParam,
 ) {
-    let gamepads = raw_inputs.gamepads.iter().collect::<Vec<_>>();
-    let mouse_motion = raw_inputs.mouse_motion.read().collect::<Vec<_>>();
-    let mouse_wheel = raw_inputs.mouse_wheel.read().collect::<Vec<_>>();
-
-    let inputs = Inputs {
-        keycodes: &raw_inputs.keycodes,
-        mouse_buttons: &raw_inputs.mouse_buttons,
-        mouse_motion: mouse_motion.as_slice(),
-        mouse_wheel: mouse_wheel.as_slice(),
-        gamepads: gamepads.as_slice(),
-        time: &raw_inputs.time,
-    };
-
-    axis.value = axis.binding.value(&inputs).unwrap_or(0.0);
+    raw_inputs.with_inputs(|inputs| {
+        for mut axis in &mut axes {
+            if contexts.allows(axis.context) {
+                axis.update(inputs);
+            } else {
+                axis.deactivate();
+            }
+        }
+    });
 }
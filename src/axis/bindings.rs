@@ -1,4 +1,8 @@
-use crate::{axis::AxisBinding, inputs::Inputs};
+use crate::{
+    axis::{AxisBinding, data::AxisBindingData},
+    describe::{BindingDescription, BindingKind, BindingMut, InputMut},
+    inputs::Inputs,
+};
 use bevy::input::{
     gamepad::{GamepadAxis, GamepadButton},
     keyboard::KeyCode,
@@ -13,6 +17,14 @@ impl AxisBinding for () {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named("None")
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::None)
+    }
 }
 
 impl AxisBinding for f32 {
@@ -23,6 +35,14 @@ impl AxisBinding for f32 {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(*self)
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Constant(*self))
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Constant(*self))
+    }
 }
 
 impl AxisBinding for KeyCode {
@@ -33,6 +53,18 @@ impl AxisBinding for KeyCode {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(*self)
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::input(*self)
+    }
+
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        Some(InputMut::Key(self))
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Key(*self))
+    }
 }
 
 impl AxisBinding for MouseButton {
@@ -43,16 +75,40 @@ impl AxisBinding for MouseButton {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(*self)
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::input(*self)
+    }
+
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        Some(InputMut::MouseButton(self))
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::MouseButton(*self))
+    }
 }
 
 impl AxisBinding for GamepadButton {
     fn value(&mut self, inputs: &Inputs) -> Option<f32> {
-        inputs.gamepads.iter().find_map(|pad| pad.get(*self))
+        inputs.gamepads.iter().find_map(|(_, pad)| pad.get(*self))
     }
 
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(*self)
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::input(*self)
+    }
+
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        Some(InputMut::GamepadButton(self))
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::GamepadButton(*self))
+    }
 }
 
 /// Binds the X-axis of mouse movement as an axis input.
@@ -72,6 +128,14 @@ impl AxisBinding for MouseX {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(*self)
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named("Mouse X")
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::MouseX)
+    }
 }
 
 /// Binds the Y-axis of mouse movement as an axis input.
@@ -91,6 +155,14 @@ impl AxisBinding for MouseY {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(*self)
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named("Mouse Y")
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::MouseY)
+    }
 }
 
 /// Binds mouse wheel movement as an axis input.
@@ -126,11 +198,21 @@ impl AxisBinding for MouseWheel {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(*self)
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named("Mouse Wheel")
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::MouseWheel {
+            px_per_line: self.px_per_line,
+        })
+    }
 }
 
 impl AxisBinding for GamepadAxis {
     fn value(&mut self, inputs: &Inputs) -> Option<f32> {
-        for pad in inputs.gamepads {
+        for (_, pad) in inputs.gamepads {
             if let Some(value) = pad.get(*self) {
                 return Some(value);
             }
@@ -141,6 +223,18 @@ impl AxisBinding for GamepadAxis {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(*self)
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::input(*self)
+    }
+
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        Some(InputMut::GamepadAxis(self))
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::GamepadAxis(*self))
+    }
 }
 
 impl AxisBinding for Box<dyn AxisBinding> {
@@ -148,7 +242,31 @@ impl AxisBinding for Box<dyn AxisBinding> {
         self.as_mut().value(inputs)
     }
 
+    fn reset(&mut self) {
+        self.as_mut().reset();
+    }
+
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         self.clone()
     }
+
+    fn describe(&self) -> BindingDescription {
+        self.as_ref().describe()
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        self.as_mut().children_mut()
+    }
+
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        self.as_mut().input_mut()
+    }
+
+    fn all_axes(&self) -> Vec<Box<dyn AxisBinding>> {
+        self.as_ref().all_axes()
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        self.as_ref().to_data()
+    }
 }
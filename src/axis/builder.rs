@@ -1,6 +1,6 @@
 use crate::{
-    Add, AxisBinding, Clamp, Divide, Invert, Normalize, RateLimit, Remap, Subtract, TriggerBinding,
-    WithTriggerBinding,
+    Add, AxisBinding, AxisThreshold, Clamp, Divide, Invert, Normalize, RateLimit, Remap, Subtract,
+    TriggerBinding, WithTriggerBinding,
     axis::{
         filters::{Deadzone, Smooth},
         modifiers::{Multiply, Transformation, WithCurve},
@@ -78,6 +78,12 @@ pub trait AxisBindingBuilder: AxisBinding + Sized {
     fn remap(self, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> Remap<Self> {
         Remap(self, in_min, in_max, out_min, out_max)
     }
+
+    /// Returns a new trigger binding that is pressed when this axis value reaches `press_at`, and released when it falls
+    /// below `release_at`.
+    fn threshold(self, press_at: f32, release_at: f32) -> AxisThreshold<Self> {
+        AxisThreshold::new(self, press_at, release_at)
+    }
 }
 
 impl<A: AxisBinding> AxisBindingBuilder for A {}
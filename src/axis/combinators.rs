@@ -1,4 +1,9 @@
-use crate::{axis::AxisBinding, inputs::Inputs, trigger::TriggerBinding};
+use crate::{
+    axis::{AxisBinding, data::AxisBindingData},
+    describe::{BindingDescription, BindingKind, BindingMut},
+    inputs::Inputs,
+    trigger::TriggerBinding,
+};
 use pastey::paste;
 
 /// A pair of axis binding, where the first axis is used for the negative direction and the second axis is used for the
@@ -24,6 +29,22 @@ impl<A1: AxisBinding + Clone, A2: AxisBinding + Clone> AxisBinding for Pair<A1,
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Pair)
+            .with_children(vec![self.0.describe(), self.1.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0), BindingMut::Axis(&mut self.1)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Pair(
+            Box::new(self.0.to_data()?),
+            Box::new(self.1.to_data()?),
+        ))
+    }
 }
 
 /// An axis binding that is only active when the given trigger binding is active.
@@ -32,7 +53,7 @@ pub struct WithTriggerBinding<A: AxisBinding, T: TriggerBinding>(pub A, pub T);
 
 impl<A: AxisBinding + Clone, T: TriggerBinding + Clone> AxisBinding for WithTriggerBinding<A, T> {
     fn value(&mut self, inputs: &Inputs) -> Option<f32> {
-        if !self.1.pressed(inputs) {
+        if !self.1.evaluate(inputs).pressed {
             return None;
         }
 
@@ -42,6 +63,24 @@ impl<A: AxisBinding + Clone, T: TriggerBinding + Clone> AxisBinding for WithTrig
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("while", vec![self.0.describe(), self.1.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![
+            BindingMut::Axis(&mut self.0),
+            BindingMut::Trigger(&mut self.1),
+        ]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::WithTriggerBinding(
+            Box::new(self.0.to_data()?),
+            self.1.to_data()?,
+        ))
+    }
 }
 
 impl<A: AxisBinding + Clone> AxisBinding for Vec<A> {
@@ -64,6 +103,24 @@ impl<A: AxisBinding + Clone> AxisBinding for Vec<A> {
         Box::new(self.clone())
     }
 
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Any)
+            .with_children(self.iter().map(|binding| binding.describe()).collect())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        self.iter_mut()
+            .map(|binding| BindingMut::Axis(binding))
+            .collect()
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        self.iter()
+            .map(|binding| binding.to_data())
+            .collect::<Option<_>>()
+            .map(AxisBindingData::Average)
+    }
+
     fn all_axes(&self) -> Vec<Box<dyn AxisBinding>> {
         self.iter().map(|b| b.clone_axis()).collect()
     }
@@ -95,6 +152,19 @@ macro_rules! impl_tuple {
                 fn all_axes(&self) -> Vec<Box<dyn AxisBinding>> {
                     vec![$(self.$a.clone_axis()),*]
                 }
+
+                fn to_data(&self) -> Option<AxisBindingData> {
+                    Some(AxisBindingData::Average(vec![$(self.$a.to_data()?),*]))
+                }
+
+                fn describe(&self) -> BindingDescription {
+                    BindingDescription::new(BindingKind::Any)
+                        .with_children(vec![$(self.$a.describe()),*])
+                }
+
+                fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+                    vec![$(BindingMut::Axis(&mut self.$a)),*]
+                }
             }
         }
     };
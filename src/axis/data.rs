@@ -0,0 +1,130 @@
+use crate::{
+    Add, AxisBinding, Clamp, Deadzone, Divide, GamepadPolicy, Invert, MouseWheel, MouseX, MouseY,
+    Multiply, Normalize, OnGamepad, Pair, RateLimit, Remap, Smooth, Subtract, TriggerBindingData,
+    WithTriggerBinding,
+};
+use bevy::input::{
+    gamepad::{GamepadAxis, GamepadButton},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+};
+
+/// A plain data description of an axis binding. Every built-in axis binding can be converted to this type with
+/// [AxisBinding::to_data] and back with [AxisBindingData::into_binding].
+///
+/// With the `serialize` feature enabled, this type implements `Serialize` and `Deserialize`, so bindings can be stored
+/// in any serde format.
+///
+/// Tuples and vecs are both described as [AxisBindingData::Average]. Bindings that wrap closures or curves
+/// ([WithCurve](crate::WithCurve), [Transformation](crate::Transformation)) and an [OnGamepad](crate::OnGamepad) scoped
+/// to a specific gamepad entity have no data representation.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AxisBinding, AxisBindingBuilder, AxisBindingData, Pair};
+/// let binding = (Pair(KeyCode::KeyA, KeyCode::KeyD), GamepadAxis::LeftStickX.deadzone(0.1));
+/// let data = binding.to_data().unwrap();
+///
+/// assert_eq!(
+///     data,
+///     AxisBindingData::Average(vec![
+///         AxisBindingData::Pair(
+///             Box::new(AxisBindingData::Key(KeyCode::KeyA)),
+///             Box::new(AxisBindingData::Key(KeyCode::KeyD)),
+///         ),
+///         AxisBindingData::Deadzone(Box::new(AxisBindingData::GamepadAxis(GamepadAxis::LeftStickX)), 0.1),
+///     ])
+/// );
+///
+/// let restored = data.clone().into_binding();
+/// assert_eq!(restored.to_data(), Some(data));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum AxisBindingData {
+    None,
+    Constant(f32),
+    Key(KeyCode),
+    MouseButton(MouseButton),
+    GamepadButton(GamepadButton),
+    GamepadAxis(GamepadAxis),
+    MouseX,
+    MouseY,
+    MouseWheel {
+        px_per_line: f32,
+    },
+    Average(Vec<AxisBindingData>),
+    Pair(Box<AxisBindingData>, Box<AxisBindingData>),
+    WithTriggerBinding(Box<AxisBindingData>, TriggerBindingData),
+    Deadzone(Box<AxisBindingData>, f32),
+    Smooth {
+        binding: Box<AxisBindingData>,
+        tau: f32,
+    },
+    Normalize(Box<AxisBindingData>, Box<AxisBindingData>),
+    RateLimit {
+        binding: Box<AxisBindingData>,
+        max_rate: f32,
+    },
+    Clamp(Box<AxisBindingData>, f32, f32),
+    Multiply(Box<AxisBindingData>, Box<AxisBindingData>),
+    Divide(Box<AxisBindingData>, Box<AxisBindingData>),
+    Add(Box<AxisBindingData>, Box<AxisBindingData>),
+    Subtract(Box<AxisBindingData>, Box<AxisBindingData>),
+    Invert(Box<AxisBindingData>),
+    Remap(Box<AxisBindingData>, f32, f32, f32, f32),
+    OnGamepad(Box<AxisBindingData>, GamepadPolicy),
+}
+
+impl AxisBindingData {
+    /// Builds the binding described by this data.
+    pub fn into_binding(self) -> Box<dyn AxisBinding> {
+        match self {
+            AxisBindingData::None => Box::new(()),
+            AxisBindingData::Constant(value) => Box::new(value),
+            AxisBindingData::Key(key) => Box::new(key),
+            AxisBindingData::MouseButton(button) => Box::new(button),
+            AxisBindingData::GamepadButton(button) => Box::new(button),
+            AxisBindingData::GamepadAxis(axis) => Box::new(axis),
+            AxisBindingData::MouseX => Box::new(MouseX),
+            AxisBindingData::MouseY => Box::new(MouseY),
+            AxisBindingData::MouseWheel { px_per_line } => Box::new(MouseWheel { px_per_line }),
+            AxisBindingData::Average(all) => Box::new(
+                all.into_iter()
+                    .map(AxisBindingData::into_binding)
+                    .collect::<Vec<_>>(),
+            ),
+            AxisBindingData::Pair(a, b) => Box::new(Pair(a.into_binding(), b.into_binding())),
+            AxisBindingData::WithTriggerBinding(a, t) => {
+                Box::new(WithTriggerBinding(a.into_binding(), t.into_binding()))
+            }
+            AxisBindingData::Deadzone(a, threshold) => {
+                Box::new(Deadzone(a.into_binding(), threshold))
+            }
+            AxisBindingData::Smooth { binding, tau } => {
+                Box::new(Smooth::new(binding.into_binding(), tau))
+            }
+            AxisBindingData::Normalize(a, b) => {
+                Box::new(Normalize(a.into_binding(), b.into_binding()))
+            }
+            AxisBindingData::RateLimit { binding, max_rate } => {
+                Box::new(RateLimit::new(binding.into_binding(), max_rate))
+            }
+            AxisBindingData::Clamp(a, min, max) => Box::new(Clamp(a.into_binding(), min, max)),
+            AxisBindingData::Multiply(a, b) => {
+                Box::new(Multiply(a.into_binding(), b.into_binding()))
+            }
+            AxisBindingData::Divide(a, b) => Box::new(Divide(a.into_binding(), b.into_binding())),
+            AxisBindingData::Add(a, b) => Box::new(Add(a.into_binding(), b.into_binding())),
+            AxisBindingData::Subtract(a, b) => {
+                Box::new(Subtract(a.into_binding(), b.into_binding()))
+            }
+            AxisBindingData::Invert(a) => Box::new(Invert(a.into_binding())),
+            AxisBindingData::Remap(a, in_min, in_max, out_min, out_max) => {
+                Box::new(Remap(a.into_binding(), in_min, in_max, out_min, out_max))
+            }
+            AxisBindingData::OnGamepad(a, policy) => Box::new(OnGamepad(a.into_binding(), policy)),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{AxisBinding, inputs::Inputs};
+use crate::{AxisBinding, AxisBindingData, BindingDescription, BindingMut, inputs::Inputs};
 
 /// A filter that only allows axis values that exceed a certain deadzone threshold.
 #[derive(Clone, Copy)]
@@ -18,6 +18,22 @@ impl<A: AxisBinding + Clone> AxisBinding for Deadzone<A> {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("deadzone", vec![self.0.describe()])
+            .with_parameter("threshold", self.1)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Deadzone(
+            Box::new(self.0.to_data()?),
+            self.1,
+        ))
+    }
 }
 
 /// A filter that smooths axis values using
@@ -55,9 +71,30 @@ impl<A: AxisBinding + Clone> AxisBinding for Smooth<A> {
         Some(value)
     }
 
+    fn reset(&mut self) {
+        self.previous_value = 0.0;
+        self.binding.reset();
+    }
+
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("smooth", vec![self.binding.describe()])
+            .with_parameter("tau", self.tau)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Smooth {
+            binding: Box::new(self.binding.to_data()?),
+            tau: self.tau,
+        })
+    }
 }
 
 /// A filter that normalizes the axis value so that the combined magnitude of the two axes is at most 1.0.
@@ -85,6 +122,24 @@ impl<A: AxisBinding + Clone, Perpendicular: AxisBinding + Clone> AxisBinding
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier(
+            "normalized with",
+            vec![self.0.describe(), self.1.describe()],
+        )
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0), BindingMut::Axis(&mut self.1)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Normalize(
+            Box::new(self.0.to_data()?),
+            Box::new(self.1.to_data()?),
+        ))
+    }
 }
 
 /// Limits the rate of change of an axis value to a maximum delta per second.
@@ -127,9 +182,30 @@ impl<A: AxisBinding + Clone> AxisBinding for RateLimit<A> {
         Some(value)
     }
 
+    fn reset(&mut self) {
+        self.previous_value = 0.0;
+        self.binding.reset();
+    }
+
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("rate limit", vec![self.binding.describe()])
+            .with_parameter("max rate", self.max_rate)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::RateLimit {
+            binding: Box::new(self.binding.to_data()?),
+            max_rate: self.max_rate,
+        })
+    }
 }
 
 /// Clamps the binding value to a specified range.
@@ -153,4 +229,22 @@ impl<A: AxisBinding + Clone> AxisBinding for Clamp<A> {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("clamp", vec![self.0.describe()])
+            .with_parameter("min", self.1)
+            .with_parameter("max", self.2)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Clamp(
+            Box::new(self.0.to_data()?),
+            self.1,
+            self.2,
+        ))
+    }
 }
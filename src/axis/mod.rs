@@ -1,11 +1,23 @@
-use crate::inputs::Inputs;
-use bevy::ecs::resource::Resource;
+use crate::{
+    axis::data::AxisBindingData,
+    clock::InputClock,
+    context::InputContext,
+    describe::{BindingDescription, BindingMut, InputMut},
+    gamepad::GamepadPolicy,
+    inputs::Inputs,
+    registry::{ActionState, RegistryAction},
+};
+use bevy::{
+    ecs::{component::Component, resource::Resource},
+    utils::prelude::ShortName,
+};
 use dyn_clone::DynClone;
 use std::{any::Any, marker::PhantomData};
 
 pub mod bindings;
 pub mod builder;
 pub mod combinators;
+pub mod data;
 pub mod filters;
 pub mod modifiers;
 
@@ -14,6 +26,14 @@ dyn_clone::clone_trait_object!(AxisBinding);
 pub trait AxisBinding: DynClone + Any + Send + Sync + 'static {
     fn value(&mut self, inputs: &Inputs) -> Option<f32>;
 
+    /// Clears the state of a stateful binding, e.g. the previous value of a [Smooth](crate::Smooth). This is called
+    /// while the axis's context is inactive. The default implementation resets the children.
+    fn reset(&mut self) {
+        for child in self.children_mut() {
+            child.reset();
+        }
+    }
+
     /// Clones the inner value and returns it as a boxed trait object.
     fn clone_axis(&self) -> Box<dyn AxisBinding>;
 
@@ -46,9 +66,31 @@ pub trait AxisBinding: DynClone + Any + Send + Sync + 'static {
     fn all_axes(&self) -> Vec<Box<dyn AxisBinding>> {
         vec![self.clone_axis()]
     }
+
+    /// Returns a plain data description of this binding, or `None` if this binding (or any of its children) can't be
+    /// described as data. See [AxisBindingData].
+    fn to_data(&self) -> Option<AxisBindingData> {
+        None
+    }
+
+    /// Returns a structured, human-readable description of this binding. See [BindingDescription].
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named(ShortName::of::<Self>().to_string())
+    }
+
+    /// Returns mutable references to the direct children of this binding. Leaf bindings return an empty vec.
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        Vec::new()
+    }
+
+    /// Returns a mutable reference to the physical input if this binding is a single input, e.g. a key.
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        None
+    }
 }
 
-/// A resource that holds the current value and bindings for an axis.
+/// A resource that holds the current value and bindings for an axis. It can also be used as a component, see
+/// [`AppExt::add_axis_component`](crate::AppExt::add_axis_component).
 ///
 /// # Examples
 /// Reading the value:
@@ -68,11 +110,17 @@ pub trait AxisBinding: DynClone + Any + Send + Sync + 'static {
 /// fn system(mut axis: ResMut<Axis<MyAxis>>) {
 ///     axis.set_binding(KeyCode::KeyW);
 /// }
-#[derive(Resource)]
+/// ```
+#[derive(Resource, Component)]
 pub struct Axis<A> {
     axis: PhantomData<A>,
     pub(crate) value: f32,
+    pub(crate) previous_value: f32,
     pub(crate) binding: Box<dyn AxisBinding>,
+    pub(crate) gamepad: GamepadPolicy,
+    pub(crate) clock: Option<InputClock>,
+    pub(crate) context: Option<InputContext>,
+    pub(crate) priority: i32,
 }
 
 impl<A> Axis<A> {
@@ -80,10 +128,21 @@ impl<A> Axis<A> {
         Self {
             axis: PhantomData,
             value: 0.0,
+            previous_value: 0.0,
             binding: Box::new(binding),
+            gamepad: GamepadPolicy::Any,
+            clock: None,
+            context: None,
+            priority: 0,
         }
     }
 
+    /// Returns the axis with its binding scoped to the gamepads allowed by the given policy.
+    pub fn with_gamepad(mut self, policy: GamepadPolicy) -> Self {
+        self.gamepad = policy;
+        self
+    }
+
     /// Get current value of the axis.
     pub fn value(&self) -> f32 {
         self.value
@@ -96,4 +155,140 @@ impl<A> Axis<A> {
     pub fn set_binding(&mut self, binding: impl AxisBinding) {
         self.binding = Box::new(binding);
     }
+
+    /// Replaces one slot of the binding. The binding is split with [AxisBinding::all_axes] and the slot at the given
+    /// index is replaced. If the index is out of bounds, the new binding is added as an extra slot.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{Axis, AxisBinding};
+    /// # struct MyAxis;
+    /// let mut axis = Axis::<MyAxis>::new((KeyCode::KeyW, GamepadAxis::LeftStickY));
+    /// axis.replace_slot(0, KeyCode::ArrowUp);
+    ///
+    /// let slots = axis.binding().all_axes();
+    /// assert_eq!(slots[0].as_any().downcast_ref::<KeyCode>(), Some(&KeyCode::ArrowUp));
+    /// assert_eq!(slots[1].as_any().downcast_ref::<GamepadAxis>(), Some(&GamepadAxis::LeftStickY));
+    /// ```
+    pub fn replace_slot(&mut self, slot: usize, binding: impl AxisBinding) {
+        let mut all = self.binding.as_ref().all_axes();
+        let binding = Box::new(binding) as Box<dyn AxisBinding>;
+
+        if let Some(existing) = all.get_mut(slot) {
+            *existing = binding;
+        } else {
+            all.push(binding);
+        }
+
+        self.set_binding(all);
+    }
+
+    pub fn gamepad(&self) -> GamepadPolicy {
+        self.gamepad
+    }
+
+    pub fn set_gamepad(&mut self, policy: GamepadPolicy) {
+        self.gamepad = policy;
+    }
+
+    /// Returns the axis with time-based bindings reading from the given clock instead of the app default.
+    pub fn with_clock(mut self, clock: InputClock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// The clock set with [with_clock](Self::with_clock), or `None` if the app default is used.
+    pub fn clock(&self) -> Option<InputClock> {
+        self.clock
+    }
+
+    pub fn set_clock(&mut self, clock: Option<InputClock>) {
+        self.clock = clock;
+    }
+
+    /// Returns the axis assigned to the given context. It's only evaluated while the context is active, see
+    /// [InputContexts](crate::InputContexts).
+    pub fn with_context(mut self, context: InputContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// The context set with [with_context](Self::with_context), or `None` if the axis is always active.
+    pub fn context(&self) -> Option<InputContext> {
+        self.context
+    }
+
+    pub fn set_context(&mut self, context: Option<InputContext>) {
+        self.context = context;
+    }
+
+    /// Returns the axis with the given priority. When input consumption is enabled, an active action suppresses
+    /// actions with a lower priority that share an input, see
+    /// [PressHerePlugin::with_input_consumption](crate::PressHerePlugin::with_input_consumption). Defaults to 0.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    /// Resets the value to zero without evaluating the binding, e.g. while its context is inactive.
+    pub(crate) fn deactivate(&mut self) {
+        self.binding.reset();
+        self.previous_value = self.value;
+        self.value = 0.0;
+    }
+
+    /// Resets the value to zero after it was evaluated, because a higher-priority action consumed its input.
+    pub(crate) fn suppress(&mut self) {
+        self.value = 0.0;
+    }
+
+    pub(crate) fn update(&mut self, inputs: &Inputs) {
+        let (binding, gamepad) = (&mut self.binding, self.gamepad);
+        let mut evaluate =
+            |inputs: &Inputs| inputs.with_gamepad_policy(gamepad, |inputs| binding.value(inputs));
+
+        self.previous_value = self.value;
+        self.value = match self.clock {
+            Some(clock) => inputs.with_clock(clock, evaluate),
+            None => evaluate(inputs),
+        }
+        .unwrap_or(0.0);
+    }
+}
+
+impl<A: Send + Sync + 'static> RegistryAction for Axis<A> {
+    type Binding = dyn AxisBinding;
+
+    fn binding(&self) -> &Self::Binding {
+        self.binding.as_ref()
+    }
+
+    fn set_binding(&mut self, binding: Box<Self::Binding>) {
+        self.binding = binding;
+    }
+
+    fn state(&self) -> ActionState {
+        ActionState {
+            active: self.value != 0.0,
+            priority: self.priority,
+            context: self.context,
+        }
+    }
+
+    fn describe(&self) -> BindingDescription {
+        self.binding.describe()
+    }
+
+    fn suppress(&mut self) {
+        self.suppress();
+    }
 }
@@ -1,4 +1,6 @@
-use crate::{AxisBinding, inputs::Inputs};
+use crate::{
+    AxisBinding, AxisBindingData, BindingDescription, BindingKind, BindingMut, inputs::Inputs,
+};
 use bevy::math::Curve;
 
 /// A modifier that applies a curve to the axis value. This is useful for creating non-linear input responses.
@@ -17,6 +19,14 @@ impl<A: AxisBinding + Clone, C: Curve<f32> + Clone + Send + Sync + 'static> Axis
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("curve", vec![self.0.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0)]
+    }
 }
 
 /// A modifier that applies a custom transformation function to the axis value.
@@ -42,6 +52,14 @@ impl<A: AxisBinding + Clone, F: Fn(f32) -> f32 + Clone + Send + Sync + 'static>
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("transformed", vec![self.0.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0)]
+    }
 }
 
 /// A modifier that multiplies two axis values together.
@@ -59,6 +77,22 @@ impl<A: AxisBinding + Clone, B: AxisBinding + Clone> AxisBinding for Multiply<A,
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Operator("*".into()))
+            .with_children(vec![self.0.describe(), self.1.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0), BindingMut::Axis(&mut self.1)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Multiply(
+            Box::new(self.0.to_data()?),
+            Box::new(self.1.to_data()?),
+        ))
+    }
 }
 
 /// A modifier that divides two axis values.
@@ -76,6 +110,22 @@ impl<A: AxisBinding + Clone, B: AxisBinding + Clone> AxisBinding for Divide<A, B
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Operator("/".into()))
+            .with_children(vec![self.0.describe(), self.1.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0), BindingMut::Axis(&mut self.1)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Divide(
+            Box::new(self.0.to_data()?),
+            Box::new(self.1.to_data()?),
+        ))
+    }
 }
 
 /// A modifier that adds two axis values together.
@@ -93,6 +143,22 @@ impl<A: AxisBinding + Clone, B: AxisBinding + Clone> AxisBinding for Add<A, B> {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Operator("+".into()))
+            .with_children(vec![self.0.describe(), self.1.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0), BindingMut::Axis(&mut self.1)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Add(
+            Box::new(self.0.to_data()?),
+            Box::new(self.1.to_data()?),
+        ))
+    }
 }
 
 /// A modifier that subtracts two axis values.
@@ -110,6 +176,22 @@ impl<A: AxisBinding + Clone, B: AxisBinding + Clone> AxisBinding for Subtract<A,
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Operator("-".into()))
+            .with_children(vec![self.0.describe(), self.1.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0), BindingMut::Axis(&mut self.1)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Subtract(
+            Box::new(self.0.to_data()?),
+            Box::new(self.1.to_data()?),
+        ))
+    }
 }
 
 /// A modifier that inverts the axis value.
@@ -126,6 +208,18 @@ impl<A: AxisBinding + Clone> AxisBinding for Invert<A> {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("inverted", vec![self.0.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Invert(Box::new(self.0.to_data()?)))
+    }
 }
 
 /// A modifier that remaps the axis value from one range to another.
@@ -166,4 +260,26 @@ impl<A: AxisBinding + Clone> AxisBinding for Remap<A> {
     fn clone_axis(&self) -> Box<dyn AxisBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("remap", vec![self.0.describe()])
+            .with_parameter("input min", self.1)
+            .with_parameter("input max", self.2)
+            .with_parameter("output min", self.3)
+            .with_parameter("output max", self.4)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        Some(AxisBindingData::Remap(
+            Box::new(self.0.to_data()?),
+            self.1,
+            self.2,
+            self.3,
+            self.4,
+        ))
+    }
 }
@@ -0,0 +1,310 @@
+use crate::{
+    axis::{
+        AxisBinding,
+        bindings::{MouseX, MouseY},
+    },
+    axis2::{DualAxisBinding, data::DualAxisBindingData},
+    describe::{BindingDescription, BindingKind, BindingMut},
+    inputs::Inputs,
+    trigger::{TriggerBinding, TriggerState, data::TriggerBindingData},
+};
+use bevy::{
+    input::{gamepad::GamepadAxis, keyboard::KeyCode},
+    math::{CompassOctant, Dir2, Vec2},
+};
+
+impl DualAxisBinding for Box<dyn DualAxisBinding> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        self.as_mut().value(inputs)
+    }
+
+    fn reset(&mut self) {
+        self.as_mut().reset();
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        self.clone()
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        self.as_ref().to_data()
+    }
+
+    fn describe(&self) -> BindingDescription {
+        self.as_ref().describe()
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        self.as_mut().children_mut()
+    }
+}
+
+impl DualAxisBinding for Vec2 {
+    fn value(&mut self, _: &Inputs) -> Option<Vec2> {
+        Some(*self)
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(*self)
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::Constant(*self))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named(self.to_string())
+    }
+}
+
+/// A gamepad stick, read from the first gamepad that reports both of its axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadStick {
+    Left,
+    Right,
+}
+
+impl GamepadStick {
+    /// Returns the X and Y axes of this stick.
+    pub fn axes(&self) -> (GamepadAxis, GamepadAxis) {
+        match self {
+            GamepadStick::Left => (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY),
+            GamepadStick::Right => (GamepadAxis::RightStickX, GamepadAxis::RightStickY),
+        }
+    }
+}
+
+impl DualAxisBinding for GamepadStick {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        let (x, y) = self.axes();
+        inputs
+            .gamepads
+            .iter()
+            .find_map(|(_, pad)| Some(Vec2::new(pad.get(x)?, pad.get(y)?)))
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(*self)
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::GamepadStick(*self))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        let (x, y) = self.axes();
+        let name = match self {
+            GamepadStick::Left => "Left Stick",
+            GamepadStick::Right => "Right Stick",
+        };
+
+        BindingDescription::named(name).with_children(vec![
+            BindingDescription::input(x),
+            BindingDescription::input(y),
+        ])
+    }
+}
+
+/// Four trigger bindings for up, down, left, and right, combined into a two-dimensional value. Diagonals have a length
+/// greater than 1, use [DualNormalize](crate::DualNormalize) to prevent faster diagonal movement.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{DualAxisBinding, VirtualDpad, testing::MockInputs};
+/// let mut binding = VirtualDpad::wasd();
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::KeyW).press(KeyCode::KeyA);
+/// assert_eq!(mock.with_inputs(|inputs| binding.value(inputs)), Some(Vec2::new(-1.0, 1.0)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct VirtualDpad<T: TriggerBinding> {
+    pub up: T,
+    pub down: T,
+    pub left: T,
+    pub right: T,
+}
+
+impl<T: TriggerBinding> VirtualDpad<T> {
+    pub fn new(up: T, down: T, left: T, right: T) -> Self {
+        Self {
+            up,
+            down,
+            left,
+            right,
+        }
+    }
+}
+
+impl VirtualDpad<KeyCode> {
+    /// The W, A, S, and D keys.
+    pub fn wasd() -> Self {
+        Self::new(KeyCode::KeyW, KeyCode::KeyS, KeyCode::KeyA, KeyCode::KeyD)
+    }
+
+    /// The arrow keys.
+    pub fn arrows() -> Self {
+        Self::new(
+            KeyCode::ArrowUp,
+            KeyCode::ArrowDown,
+            KeyCode::ArrowLeft,
+            KeyCode::ArrowRight,
+        )
+    }
+}
+
+impl<T: TriggerBinding + Clone> DualAxisBinding for VirtualDpad<T> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        let up = self.up.evaluate(inputs).pressed;
+        let down = self.down.evaluate(inputs).pressed;
+        let left = self.left.evaluate(inputs).pressed;
+        let right = self.right.evaluate(inputs).pressed;
+
+        if !(up || down || left || right) {
+            return None;
+        }
+
+        let axis = |negative: bool, positive: bool| positive as i8 as f32 - negative as i8 as f32;
+        Some(Vec2::new(axis(left, right), axis(down, up)))
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::VirtualDpad {
+            up: Box::new(self.up.to_data()?),
+            down: Box::new(self.down.to_data()?),
+            left: Box::new(self.left.to_data()?),
+            right: Box::new(self.right.to_data()?),
+        })
+    }
+
+    fn describe(&self) -> BindingDescription {
+        let pair = |negative: &T, positive: &T| {
+            BindingDescription::new(BindingKind::Pair)
+                .with_children(vec![negative.describe(), positive.describe()])
+        };
+
+        BindingDescription::new(BindingKind::Dual).with_children(vec![
+            pair(&self.left, &self.right),
+            pair(&self.down, &self.up),
+        ])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![
+            BindingMut::Trigger(&mut self.up),
+            BindingMut::Trigger(&mut self.down),
+            BindingMut::Trigger(&mut self.left),
+            BindingMut::Trigger(&mut self.right),
+        ]
+    }
+}
+
+/// Mouse movement as a two-dimensional value. Uses the same conventions as [MouseX] and [MouseY].
+#[derive(Clone, Copy)]
+pub struct MouseXY;
+
+impl DualAxisBinding for MouseXY {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        Some(Vec2::new(MouseX.value(inputs)?, MouseY.value(inputs)?))
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(*self)
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::MouseXY)
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named("Mouse")
+    }
+}
+
+/// A trigger binding that is pressed while a two-dimensional binding points in the given direction, e.g. "down-forward"
+/// in a fighting game motion. The value must have a length of at least `threshold`, and each of the eight directions
+/// covers an eighth of the circle.
+///
+/// # Examples
+/// ```
+/// # use bevy::{math::CompassOctant, prelude::*};
+/// # use press_here::{DualAxisBindingBuilder, GamepadStick, TriggerBinding, testing::MockInputs};
+/// let mut binding = GamepadStick::Left.direction(CompassOctant::SouthEast);
+/// let mut mock = MockInputs::new();
+///
+/// mock.set_axis(GamepadAxis::LeftStickX, 0.7).set_axis(GamepadAxis::LeftStickY, -0.7);
+/// assert!(mock.with_inputs(|inputs| binding.evaluate(inputs).just_pressed));
+/// ```
+#[derive(Clone, Copy)]
+pub struct DualAxisDirection<A: DualAxisBinding> {
+    pub binding: A,
+    pub direction: CompassOctant,
+    pub threshold: f32,
+    active: bool,
+}
+
+impl<A: DualAxisBinding> DualAxisDirection<A> {
+    /// Creates the binding with a threshold of 0.5.
+    pub fn new(binding: A, direction: CompassOctant) -> Self {
+        Self {
+            binding,
+            direction,
+            threshold: 0.5,
+            active: false,
+        }
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl<A: DualAxisBinding + Clone> TriggerBinding for DualAxisDirection<A> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+        self.active = self
+            .binding
+            .value(inputs)
+            .filter(|value| value.length() >= self.threshold)
+            .and_then(|value| Dir2::new(value).ok())
+            .is_some_and(|dir| CompassOctant::from(dir) == self.direction);
+
+        TriggerState::transition(previous, self.active)
+    }
+
+    fn reset(&mut self) {
+        self.active = false;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier(
+            format!("towards {:?}", self.direction),
+            vec![self.binding.describe()],
+        )
+        .with_parameter("threshold", self.threshold)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::DualAxis(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::DualAxisDirection {
+            binding: Box::new(self.binding.to_data()?),
+            direction: self.direction,
+            threshold: self.threshold,
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    DualAxisBinding,
+    axis2::bindings::DualAxisDirection,
+    axis2::filters::{
+        CrossDeadzone, DualNormalize, DualSmooth, HybridDeadzone, OuterDeadzone, RadialDeadzone,
+        ScaledRadialDeadzone,
+    },
+};
+use bevy::math::CompassOctant;
+
+pub trait DualAxisBindingBuilder: DualAxisBinding + Sized {
+    /// Returns a new binding that applies a radial deadzone with the given radius.
+    fn radial_deadzone(self, radius: f32) -> RadialDeadzone<Self> {
+        RadialDeadzone(self, radius)
+    }
+
+    /// Returns a new binding that applies a radial deadzone with the given radius and rescales the remaining range.
+    fn scaled_radial_deadzone(self, radius: f32) -> ScaledRadialDeadzone<Self> {
+        ScaledRadialDeadzone(self, radius)
+    }
+
+    /// Returns a new binding that applies a bowtie-shaped deadzone, where the deadzone of each component is `threshold`
+    /// times the other component. See [CrossDeadzone].
+    fn cross_deadzone(self, threshold: f32) -> CrossDeadzone<Self> {
+        CrossDeadzone(self, threshold)
+    }
+
+    /// Returns a new binding that applies a scaled radial deadzone followed by a cross deadzone.
+    fn hybrid_deadzone(self, radius: f32, threshold: f32) -> HybridDeadzone<Self> {
+        HybridDeadzone(self, radius, threshold)
+    }
+
+    /// Returns a new binding that reports lengths of `outer` or more as 1.0.
+    fn outer_deadzone(self, outer: f32) -> OuterDeadzone<Self> {
+        OuterDeadzone(self, outer)
+    }
+
+    /// Returns a new binding that limits the length of the value to at most 1.0.
+    fn dual_normalize(self) -> DualNormalize<Self> {
+        DualNormalize(self)
+    }
+
+    /// Returns a new binding that applies a smoothing filter with the given time constant to both components.
+    fn dual_smooth(self, tau: f32) -> DualSmooth<Self> {
+        DualSmooth::new(self, tau)
+    }
+
+    /// Returns a new trigger binding that is pressed while this binding points in the given direction.
+    fn direction(self, direction: CompassOctant) -> DualAxisDirection<Self> {
+        DualAxisDirection::new(self, direction)
+    }
+}
+
+impl<A: DualAxisBinding> DualAxisBindingBuilder for A {}
@@ -0,0 +1,139 @@
+use crate::{
+    axis::AxisBinding,
+    axis2::{DualAxisBinding, data::DualAxisBindingData},
+    describe::{BindingDescription, BindingKind, BindingMut},
+    inputs::Inputs,
+};
+use bevy::math::Vec2;
+use pastey::paste;
+
+/// Combines two axis bindings into a two-dimensional value, with the first binding as the X component and the second
+/// as the Y component.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{DualAxisBinding, Pair, XY, testing::MockInputs};
+/// let mut binding = XY(
+///     Pair(KeyCode::ArrowLeft, KeyCode::ArrowRight),
+///     Pair(KeyCode::ArrowDown, KeyCode::ArrowUp),
+/// );
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::ArrowRight);
+/// assert_eq!(mock.with_inputs(|inputs| binding.value(inputs)), Some(Vec2::X));
+/// ```
+#[derive(Clone, Copy)]
+pub struct XY<X: AxisBinding, Y: AxisBinding>(pub X, pub Y);
+
+impl<X: AxisBinding + Clone, Y: AxisBinding + Clone> DualAxisBinding for XY<X, Y> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        let x = self.0.value(inputs);
+        let y = self.1.value(inputs);
+
+        if x.is_none() && y.is_none() {
+            return None;
+        }
+
+        Some(Vec2::new(x.unwrap_or(0.0), y.unwrap_or(0.0)))
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::XY(
+            Box::new(self.0.to_data()?),
+            Box::new(self.1.to_data()?),
+        ))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Dual)
+            .with_children(vec![self.0.describe(), self.1.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0), BindingMut::Axis(&mut self.1)]
+    }
+}
+
+/// Averages the values of all active bindings, like the tuple implementation.
+fn average(values: impl Iterator<Item = Option<Vec2>>) -> Option<Vec2> {
+    let all = values.flatten().collect::<Vec<_>>();
+    let count = all.len();
+
+    if count > 0 {
+        Some(all.iter().sum::<Vec2>() / count as f32)
+    } else {
+        None
+    }
+}
+
+impl<A: DualAxisBinding + Clone> DualAxisBinding for Vec<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        average(self.iter_mut().map(|binding| binding.value(inputs)))
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        self.iter()
+            .map(|binding| binding.to_data())
+            .collect::<Option<_>>()
+            .map(DualAxisBindingData::Average)
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Any)
+            .with_children(self.iter().map(|binding| binding.describe()).collect())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        self.iter_mut()
+            .map(|binding| BindingMut::DualAxis(binding))
+            .collect()
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($a:expr),*) => {
+        paste! {
+            impl<$([<A$a>]: DualAxisBinding + Clone),*> DualAxisBinding for ($([<A$a>]),*) {
+                fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+                    average([$(self.$a.value(inputs)),*].into_iter())
+                }
+
+                fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+                    Box::new(self.clone())
+                }
+
+                fn to_data(&self) -> Option<DualAxisBindingData> {
+                    Some(DualAxisBindingData::Average(vec![$(self.$a.to_data()?),*]))
+                }
+
+                fn describe(&self) -> BindingDescription {
+                    BindingDescription::new(BindingKind::Any)
+                        .with_children(vec![$(self.$a.describe()),*])
+                }
+
+                fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+                    vec![$(BindingMut::DualAxis(&mut self.$a)),*]
+                }
+            }
+        }
+    };
+}
+
+impl_tuple!(0, 1);
+impl_tuple!(0, 1, 2);
+impl_tuple!(0, 1, 2, 3);
+impl_tuple!(0, 1, 2, 3, 4);
+impl_tuple!(0, 1, 2, 3, 4, 5);
+impl_tuple!(0, 1, 2, 3, 4, 5, 6);
+impl_tuple!(0, 1, 2, 3, 4, 5, 6, 7);
+impl_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
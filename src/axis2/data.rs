@@ -0,0 +1,112 @@
+use crate::{
+    AxisBindingData, CrossDeadzone, DualAxisBinding, DualNormalize, DualSmooth, GamepadStick,
+    HybridDeadzone, MouseXY, OuterDeadzone, RadialDeadzone, ScaledRadialDeadzone,
+    TriggerBindingData, VirtualDpad, XY,
+};
+use bevy::math::Vec2;
+
+/// A plain data description of a two-dimensional axis binding. Every built-in dual axis binding can be converted to
+/// this type with [DualAxisBinding::to_data] and back with [DualAxisBindingData::into_binding].
+///
+/// With the `serialize` feature enabled, this type implements `Serialize` and `Deserialize`, so bindings can be stored
+/// in any serde format.
+///
+/// Tuples and vecs are both described as [DualAxisBindingData::Average].
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{
+/// #     DualAxisBinding, DualAxisBindingBuilder, DualAxisBindingData, GamepadStick, TriggerBindingData, VirtualDpad,
+/// # };
+/// let binding = (VirtualDpad::wasd(), GamepadStick::Left.radial_deadzone(0.1));
+/// let data = binding.to_data().unwrap();
+///
+/// assert_eq!(
+///     data,
+///     DualAxisBindingData::Average(vec![
+///         DualAxisBindingData::VirtualDpad {
+///             up: Box::new(TriggerBindingData::Key(KeyCode::KeyW)),
+///             down: Box::new(TriggerBindingData::Key(KeyCode::KeyS)),
+///             left: Box::new(TriggerBindingData::Key(KeyCode::KeyA)),
+///             right: Box::new(TriggerBindingData::Key(KeyCode::KeyD)),
+///         },
+///         DualAxisBindingData::RadialDeadzone(Box::new(DualAxisBindingData::GamepadStick(GamepadStick::Left)), 0.1),
+///     ])
+/// );
+///
+/// let restored = data.clone().into_binding();
+/// assert_eq!(restored.to_data(), Some(data));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum DualAxisBindingData {
+    Constant(Vec2),
+    GamepadStick(GamepadStick),
+    MouseXY,
+    VirtualDpad {
+        up: Box<TriggerBindingData>,
+        down: Box<TriggerBindingData>,
+        left: Box<TriggerBindingData>,
+        right: Box<TriggerBindingData>,
+    },
+    XY(Box<AxisBindingData>, Box<AxisBindingData>),
+    Average(Vec<DualAxisBindingData>),
+    RadialDeadzone(Box<DualAxisBindingData>, f32),
+    ScaledRadialDeadzone(Box<DualAxisBindingData>, f32),
+    CrossDeadzone(Box<DualAxisBindingData>, f32),
+    HybridDeadzone(Box<DualAxisBindingData>, f32, f32),
+    OuterDeadzone(Box<DualAxisBindingData>, f32),
+    DualNormalize(Box<DualAxisBindingData>),
+    DualSmooth {
+        binding: Box<DualAxisBindingData>,
+        tau: f32,
+    },
+}
+
+impl DualAxisBindingData {
+    /// Builds the binding described by this data.
+    pub fn into_binding(self) -> Box<dyn DualAxisBinding> {
+        match self {
+            DualAxisBindingData::Constant(value) => Box::new(value),
+            DualAxisBindingData::GamepadStick(stick) => Box::new(stick),
+            DualAxisBindingData::MouseXY => Box::new(MouseXY),
+            DualAxisBindingData::VirtualDpad {
+                up,
+                down,
+                left,
+                right,
+            } => Box::new(VirtualDpad::new(
+                up.into_binding(),
+                down.into_binding(),
+                left.into_binding(),
+                right.into_binding(),
+            )),
+            DualAxisBindingData::XY(x, y) => Box::new(XY(x.into_binding(), y.into_binding())),
+            DualAxisBindingData::Average(all) => Box::new(
+                all.into_iter()
+                    .map(DualAxisBindingData::into_binding)
+                    .collect::<Vec<_>>(),
+            ),
+            DualAxisBindingData::RadialDeadzone(a, radius) => {
+                Box::new(RadialDeadzone(a.into_binding(), radius))
+            }
+            DualAxisBindingData::ScaledRadialDeadzone(a, radius) => {
+                Box::new(ScaledRadialDeadzone(a.into_binding(), radius))
+            }
+            DualAxisBindingData::CrossDeadzone(a, threshold) => {
+                Box::new(CrossDeadzone(a.into_binding(), threshold))
+            }
+            DualAxisBindingData::HybridDeadzone(a, radius, threshold) => {
+                Box::new(HybridDeadzone(a.into_binding(), radius, threshold))
+            }
+            DualAxisBindingData::OuterDeadzone(a, outer) => {
+                Box::new(OuterDeadzone(a.into_binding(), outer))
+            }
+            DualAxisBindingData::DualNormalize(a) => Box::new(DualNormalize(a.into_binding())),
+            DualAxisBindingData::DualSmooth { binding, tau } => {
+                Box::new(DualSmooth::new(binding.into_binding(), tau))
+            }
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    axis2::{DualAxisBinding, data::DualAxisBindingData},
+    describe::{BindingDescription, BindingMut},
+    inputs::Inputs,
+};
+use bevy::math::Vec2;
+
+/// A filter that only allows values whose length exceeds the given radius. Unlike applying a
+/// [Deadzone](crate::Deadzone) to each axis, this doesn't snap diagonal input to the axes.
+#[derive(Clone, Copy)]
+pub struct RadialDeadzone<A: DualAxisBinding>(pub A, pub f32);
+
+impl<A: DualAxisBinding + Clone> DualAxisBinding for RadialDeadzone<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        let value = self.0.value(inputs)?;
+
+        if value.length() < self.1 {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::RadialDeadzone(
+            Box::new(self.0.to_data()?),
+            self.1,
+        ))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("radial deadzone", vec![self.0.describe()])
+            .with_parameter("radius", self.1)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::DualAxis(&mut self.0)]
+    }
+}
+
+/// Like [RadialDeadzone], but rescales the remaining range so that the output length starts at 0.0 at the edge of the
+/// deadzone instead of jumping to the radius. This keeps fine aiming precise near the center.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{DualAxisBinding, DualAxisBindingBuilder, GamepadStick, testing::MockInputs};
+/// let mut binding = GamepadStick::Left.scaled_radial_deadzone(0.2);
+/// let mut mock = MockInputs::new();
+///
+/// mock.set_axis(GamepadAxis::LeftStickX, 0.6).set_axis(GamepadAxis::LeftStickY, 0.0);
+/// let value = mock.with_inputs(|inputs| binding.value(inputs)).unwrap();
+/// assert!((value.x - 0.5).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ScaledRadialDeadzone<A: DualAxisBinding>(pub A, pub f32);
+
+impl<A: DualAxisBinding + Clone> DualAxisBinding for ScaledRadialDeadzone<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        scaled_radial(self.0.value(inputs)?, self.1)
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::ScaledRadialDeadzone(
+            Box::new(self.0.to_data()?),
+            self.1,
+        ))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("scaled radial deadzone", vec![self.0.describe()])
+            .with_parameter("radius", self.1)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::DualAxis(&mut self.0)]
+    }
+}
+
+/// A bowtie-shaped deadzone. Each component has a scaled deadzone of `threshold` times the length of the other
+/// component, so the dead area widens towards the edge along each axis. Small movements along one axis are ignored
+/// while the other axis is pushed, which makes it easy to hold a straight direction, while input near the center is
+/// left untouched. Combine it with a radial deadzone to remove drift, see [HybridDeadzone].
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{DualAxisBinding, DualAxisBindingBuilder, GamepadStick, testing::MockInputs};
+/// let mut binding = GamepadStick::Left.cross_deadzone(0.2);
+/// let mut mock = MockInputs::new();
+///
+/// mock.set_axis(GamepadAxis::LeftStickX, 1.0).set_axis(GamepadAxis::LeftStickY, 0.1);
+/// assert_eq!(mock.with_inputs(|inputs| binding.value(inputs)), Some(Vec2::X));
+///
+/// // Near the center, the deadzone is small.
+/// mock.set_axis(GamepadAxis::LeftStickX, 0.1).set_axis(GamepadAxis::LeftStickY, 0.0);
+/// assert_eq!(mock.with_inputs(|inputs| binding.value(inputs)), Some(Vec2::new(0.1, 0.0)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct CrossDeadzone<A: DualAxisBinding>(pub A, pub f32);
+
+impl<A: DualAxisBinding + Clone> DualAxisBinding for CrossDeadzone<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        cross(self.0.value(inputs)?, self.1)
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::CrossDeadzone(
+            Box::new(self.0.to_data()?),
+            self.1,
+        ))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("cross deadzone", vec![self.0.describe()])
+            .with_parameter("threshold", self.1)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::DualAxis(&mut self.0)]
+    }
+}
+
+/// A filter that applies a [ScaledRadialDeadzone] with the given radius, followed by a [CrossDeadzone] with the given
+/// threshold. The radial part removes stick drift around the center, and the small cross part snaps nearly straight
+/// input to the axes.
+#[derive(Clone, Copy)]
+pub struct HybridDeadzone<A: DualAxisBinding>(pub A, pub f32, pub f32);
+
+impl<A: DualAxisBinding + Clone> DualAxisBinding for HybridDeadzone<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        cross(scaled_radial(self.0.value(inputs)?, self.1)?, self.2)
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::HybridDeadzone(
+            Box::new(self.0.to_data()?),
+            self.1,
+            self.2,
+        ))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("hybrid deadzone", vec![self.0.describe()])
+            .with_parameter("radius", self.1)
+            .with_parameter("threshold", self.2)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::DualAxis(&mut self.0)]
+    }
+}
+
+/// A filter that saturates the value, so that a length of `outer` or more is reported as 1.0. Lengths below `outer`
+/// are scaled up accordingly. This lets worn sticks that don't reach the edge still produce full input.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{DualAxisBinding, DualAxisBindingBuilder, GamepadStick, testing::MockInputs};
+/// let mut binding = GamepadStick::Left.outer_deadzone(0.9);
+/// let mut mock = MockInputs::new();
+///
+/// mock.set_axis(GamepadAxis::LeftStickX, 0.0).set_axis(GamepadAxis::LeftStickY, 0.95);
+/// assert_eq!(mock.with_inputs(|inputs| binding.value(inputs)), Some(Vec2::Y));
+/// ```
+#[derive(Clone, Copy)]
+pub struct OuterDeadzone<A: DualAxisBinding>(pub A, pub f32);
+
+impl<A: DualAxisBinding + Clone> DualAxisBinding for OuterDeadzone<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        let value = self.0.value(inputs)?;
+        if self.1 <= 0.0 {
+            return Some(value.normalize_or_zero());
+        }
+        Some((value / self.1).clamp_length_max(1.0))
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::OuterDeadzone(
+            Box::new(self.0.to_data()?),
+            self.1,
+        ))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("outer deadzone", vec![self.0.describe()])
+            .with_parameter("outer", self.1)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::DualAxis(&mut self.0)]
+    }
+}
+
+/// Removes the inner `radius` of the value and rescales the rest to the full range. Returns `None` inside the deadzone.
+fn scaled_radial(value: Vec2, radius: f32) -> Option<Vec2> {
+    let length = value.length();
+    if length <= radius || length == 0.0 {
+        return None;
+    }
+
+    Some(value / length * rescale(length, radius))
+}
+
+/// Removes a deadzone of `threshold` times the other component from each component and rescales the rest to the full
+/// range. Returns `None` if both components are inside the deadzone.
+fn cross(value: Vec2, threshold: f32) -> Option<Vec2> {
+    let component = |c: f32, other: f32| {
+        let deadzone = threshold * other.abs();
+        if c.abs() <= deadzone {
+            0.0
+        } else {
+            c.signum() * rescale(c.abs(), deadzone)
+        }
+    };
+
+    let value = Vec2::new(component(value.x, value.y), component(value.y, value.x));
+    (value != Vec2::ZERO).then_some(value)
+}
+
+/// Maps `magnitude` from the range between `deadzone` and 1.0 to the range between 0.0 and 1.0.
+fn rescale(magnitude: f32, deadzone: f32) -> f32 {
+    ((magnitude - deadzone) / (1.0 - deadzone).max(f32::EPSILON)).min(1.0)
+}
+
+/// A filter that limits the length of the value to at most 1.0, e.g. so that diagonal movement isn't faster.
+#[derive(Clone, Copy)]
+pub struct DualNormalize<A: DualAxisBinding>(pub A);
+
+impl<A: DualAxisBinding + Clone> DualAxisBinding for DualNormalize<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        Some(self.0.value(inputs)?.clamp_length_max(1.0))
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::DualNormalize(Box::new(
+            self.0.to_data()?,
+        )))
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("normalized", vec![self.0.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::DualAxis(&mut self.0)]
+    }
+}
+
+/// A filter that smooths two-dimensional values using
+/// [exponential smoothing](https://en.wikipedia.org/wiki/Exponential_smoothing#Basic_(simple)_exponential_smoothing).
+/// Both components are smoothed together, so the direction doesn't drift.
+///
+/// `tau` is the time constant that controls the amount of smoothing, see [Smooth](crate::Smooth).
+#[derive(Clone, Copy)]
+pub struct DualSmooth<A: DualAxisBinding> {
+    pub binding: A,
+    pub tau: f32,
+    previous_value: Vec2,
+}
+
+impl<A: DualAxisBinding> DualSmooth<A> {
+    pub fn new(binding: A, tau: f32) -> Self {
+        Self {
+            binding,
+            tau,
+            previous_value: Vec2::ZERO,
+        }
+    }
+}
+
+impl<A: DualAxisBinding + Clone> DualAxisBinding for DualSmooth<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2> {
+        let target = self.binding.value(inputs).unwrap_or(Vec2::ZERO);
+        let dt = inputs.time.delta_secs();
+
+        let alpha = 1.0 - (-dt / self.tau).exp();
+
+        let value = self.previous_value + alpha * (target - self.previous_value);
+        self.previous_value = value;
+
+        Some(value)
+    }
+
+    fn reset(&mut self) {
+        self.previous_value = Vec2::ZERO;
+        self.binding.reset();
+    }
+
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        Some(DualAxisBindingData::DualSmooth {
+            binding: Box::new(self.binding.to_data()?),
+            tau: self.tau,
+        })
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("smooth", vec![self.binding.describe()])
+            .with_parameter("tau", self.tau)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::DualAxis(&mut self.binding)]
+    }
+}
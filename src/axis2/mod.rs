@@ -0,0 +1,230 @@
+use crate::{
+    clock::InputClock,
+    context::InputContext,
+    describe::{BindingDescription, BindingMut},
+    gamepad::GamepadPolicy,
+    inputs::Inputs,
+    registry::{ActionState, RegistryAction},
+};
+use bevy::{
+    ecs::{component::Component, resource::Resource},
+    math::Vec2,
+    utils::prelude::ShortName,
+};
+use data::DualAxisBindingData;
+use dyn_clone::DynClone;
+use std::{any::Any, marker::PhantomData};
+
+pub mod bindings;
+pub mod builder;
+pub mod combinators;
+pub mod data;
+pub mod filters;
+
+dyn_clone::clone_trait_object!(DualAxisBinding);
+
+/// A binding that produces a two-dimensional value, e.g. a gamepad stick. Both components are evaluated together, so
+/// 2D filters like [RadialDeadzone](crate::RadialDeadzone) can work on the whole vector.
+pub trait DualAxisBinding: DynClone + Any + Send + Sync + 'static {
+    fn value(&mut self, inputs: &Inputs) -> Option<Vec2>;
+
+    /// Clears the state of a stateful binding, e.g. the previous value of a [DualSmooth](crate::DualSmooth). This is
+    /// called while the axis's context is inactive. The default implementation resets the children.
+    fn reset(&mut self) {
+        for child in self.children_mut() {
+            child.reset();
+        }
+    }
+
+    /// Clones the inner value and returns it as a boxed trait object.
+    fn clone_dual_axis(&self) -> Box<dyn DualAxisBinding>;
+
+    /// Returns a plain data description of this binding, or `None` if this binding (or any of its children) can't be
+    /// described as data. See [DualAxisBindingData].
+    fn to_data(&self) -> Option<DualAxisBindingData> {
+        None
+    }
+
+    /// Returns a structured, human-readable description of this binding. See [BindingDescription].
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named(ShortName::of::<Self>().to_string())
+    }
+
+    /// Returns mutable references to the direct children of this binding. Leaf bindings return an empty vec.
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        Vec::new()
+    }
+}
+
+/// A resource that holds the current two-dimensional value and binding for an axis pair, e.g. movement. It can also be
+/// used as a component, see [`AppExt::add_axis2_component`](crate::AppExt::add_axis2_component).
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, Axis2, DualAxisBindingBuilder, GamepadStick, VirtualDpad, testing::MockAppExt};
+/// let mut app = App::new();
+/// app.init_mock_inputs().add_axis2::<Move>((
+///     VirtualDpad::wasd().dual_normalize(),
+///     GamepadStick::Left.radial_deadzone(0.1),
+/// ));
+///
+/// app.press(KeyCode::KeyW).press(KeyCode::KeyD).update();
+/// let value = app.world().resource::<Axis2<Move>>().value();
+/// assert!((value.length() - 1.0).abs() < 1e-6);
+///
+/// # struct Move;
+/// ```
+#[derive(Resource, Component)]
+pub struct Axis2<A> {
+    axis: PhantomData<A>,
+    pub(crate) value: Vec2,
+    pub(crate) previous_value: Vec2,
+    pub(crate) binding: Box<dyn DualAxisBinding>,
+    pub(crate) gamepad: GamepadPolicy,
+    pub(crate) clock: Option<InputClock>,
+    pub(crate) context: Option<InputContext>,
+    pub(crate) priority: i32,
+}
+
+impl<A> Axis2<A> {
+    pub fn new(binding: impl DualAxisBinding + 'static) -> Self {
+        Self {
+            axis: PhantomData,
+            value: Vec2::ZERO,
+            previous_value: Vec2::ZERO,
+            binding: Box::new(binding),
+            gamepad: GamepadPolicy::Any,
+            clock: None,
+            context: None,
+            priority: 0,
+        }
+    }
+
+    /// Returns the axis with its binding scoped to the gamepads allowed by the given policy.
+    pub fn with_gamepad(mut self, policy: GamepadPolicy) -> Self {
+        self.gamepad = policy;
+        self
+    }
+
+    /// Returns the axis with time-based bindings reading from the given clock instead of the app default.
+    pub fn with_clock(mut self, clock: InputClock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Get current value of the axis.
+    pub fn value(&self) -> Vec2 {
+        self.value
+    }
+
+    pub fn binding(&self) -> &dyn DualAxisBinding {
+        self.binding.as_ref()
+    }
+
+    pub fn set_binding(&mut self, binding: impl DualAxisBinding) {
+        self.binding = Box::new(binding);
+    }
+
+    pub fn gamepad(&self) -> GamepadPolicy {
+        self.gamepad
+    }
+
+    pub fn set_gamepad(&mut self, policy: GamepadPolicy) {
+        self.gamepad = policy;
+    }
+
+    /// The clock set with [with_clock](Self::with_clock), or `None` if the app default is used.
+    pub fn clock(&self) -> Option<InputClock> {
+        self.clock
+    }
+
+    pub fn set_clock(&mut self, clock: Option<InputClock>) {
+        self.clock = clock;
+    }
+
+    /// Returns the axis assigned to the given context. It's only evaluated while the context is active, see
+    /// [InputContexts](crate::InputContexts).
+    pub fn with_context(mut self, context: InputContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// The context set with [with_context](Self::with_context), or `None` if the axis is always active.
+    pub fn context(&self) -> Option<InputContext> {
+        self.context
+    }
+
+    pub fn set_context(&mut self, context: Option<InputContext>) {
+        self.context = context;
+    }
+
+    /// Returns the axis with the given priority. When input consumption is enabled, an active action suppresses
+    /// actions with a lower priority that share an input, see
+    /// [PressHerePlugin::with_input_consumption](crate::PressHerePlugin::with_input_consumption). Defaults to 0.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    /// Resets the value to zero without evaluating the binding, e.g. while its context is inactive.
+    pub(crate) fn deactivate(&mut self) {
+        self.binding.reset();
+        self.previous_value = self.value;
+        self.value = Vec2::ZERO;
+    }
+
+    /// Resets the value to zero after it was evaluated, because a higher-priority action consumed its input.
+    pub(crate) fn suppress(&mut self) {
+        self.value = Vec2::ZERO;
+    }
+
+    pub(crate) fn update(&mut self, inputs: &Inputs) {
+        let (binding, gamepad) = (&mut self.binding, self.gamepad);
+        let mut evaluate =
+            |inputs: &Inputs| inputs.with_gamepad_policy(gamepad, |inputs| binding.value(inputs));
+
+        self.previous_value = self.value;
+        self.value = match self.clock {
+            Some(clock) => inputs.with_clock(clock, evaluate),
+            None => evaluate(inputs),
+        }
+        .unwrap_or(Vec2::ZERO);
+    }
+}
+
+impl<A: Send + Sync + 'static> RegistryAction for Axis2<A> {
+    type Binding = dyn DualAxisBinding;
+
+    fn binding(&self) -> &Self::Binding {
+        self.binding.as_ref()
+    }
+
+    fn set_binding(&mut self, binding: Box<Self::Binding>) {
+        self.binding = binding;
+    }
+
+    fn state(&self) -> ActionState {
+        ActionState {
+            active: self.value != Vec2::ZERO,
+            priority: self.priority,
+            context: self.context,
+        }
+    }
+
+    fn describe(&self) -> BindingDescription {
+        self.binding.describe()
+    }
+
+    fn suppress(&mut self) {
+        self.suppress();
+    }
+}
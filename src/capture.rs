@@ -0,0 +1,468 @@
+use crate::{
+    Invert, Pair,
+    axis::{Axis, AxisBinding},
+    describe::{BindingKind, BindingMut},
+    inputs::InputSource,
+    trigger::{Trigger, TriggerBinding},
+};
+use bevy::{
+    ecs::{
+        entity::Entity,
+        message::{Message, MessageWriter},
+        resource::Resource,
+        system::{Query, Res, ResMut},
+    },
+    input::{
+        ButtonInput,
+        gamepad::{Gamepad, GamepadInput},
+        keyboard::KeyCode,
+        mouse::MouseButton,
+    },
+    time::{Real, Time},
+    utils::prelude::ShortName,
+};
+use std::{any::TypeId, time::Duration};
+
+/// The axis or trigger slot that a capture will replace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaptureTarget {
+    action: TypeId,
+    name: String,
+    kind: CaptureKind,
+    slot: usize,
+    side: Option<PairSide>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CaptureKind {
+    Trigger,
+    Axis,
+}
+
+/// A side of a [Pair] binding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PairSide {
+    /// The first binding, which produces negative values.
+    Negative,
+    /// The second binding, which produces positive values.
+    Positive,
+}
+
+impl CaptureTarget {
+    /// The short type name of the targeted action.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The index of the binding slot being replaced. See [TriggerBinding::all_triggers] and
+    /// [AxisBinding::all_axes].
+    pub fn slot(&self) -> usize {
+        self.slot
+    }
+
+    /// The side of a [Pair] slot being replaced, see [CaptureRequest::axis_side].
+    pub fn side(&self) -> Option<PairSide> {
+        self.side
+    }
+
+    /// Returns true if this target is a slot of [`Trigger<T>`].
+    pub fn is_trigger<T: 'static>(&self) -> bool {
+        self.kind == CaptureKind::Trigger && self.action == TypeId::of::<T>()
+    }
+
+    /// Returns true if this target is a slot of [`Axis<A>`].
+    pub fn is_axis<A: 'static>(&self) -> bool {
+        self.kind == CaptureKind::Axis && self.action == TypeId::of::<A>()
+    }
+}
+
+/// A request to capture the next input for a trigger or axis slot. Start it with [BindingCapture::start].
+///
+/// By default, `Escape` cancels the capture, no inputs are excluded, there is no timeout, and gamepad axes must be
+/// pushed past `0.5` to be captured.
+///
+/// Trigger captures only listen for buttons and keys. Axis captures also listen for gamepad axes.
+#[derive(Clone, Debug)]
+pub struct CaptureRequest {
+    target: CaptureTarget,
+    cancel_key: Option<KeyCode>,
+    excluded: Vec<InputSource>,
+    timeout: Option<Duration>,
+    axis_threshold: f32,
+}
+
+impl CaptureRequest {
+    fn new<T: 'static>(kind: CaptureKind, slot: usize) -> Self {
+        Self {
+            target: CaptureTarget {
+                action: TypeId::of::<T>(),
+                name: ShortName::of::<T>().to_string(),
+                kind,
+                slot,
+                side: None,
+            },
+            cancel_key: Some(KeyCode::Escape),
+            excluded: Vec::new(),
+            timeout: None,
+            axis_threshold: 0.5,
+        }
+    }
+
+    /// Captures an input for the given slot of [`Trigger<T>`].
+    pub fn trigger<T: 'static>(slot: usize) -> Self {
+        Self::new::<T>(CaptureKind::Trigger, slot)
+    }
+
+    /// Captures an input for the given slot of [`Axis<A>`].
+    pub fn axis<A: 'static>(slot: usize) -> Self {
+        Self::new::<A>(CaptureKind::Axis, slot)
+    }
+
+    /// Captures an input for one side of the given slot of [`Axis<A>`], if the slot is a [Pair]. The other side is
+    /// kept.
+    pub fn axis_side<A: 'static>(slot: usize, side: PairSide) -> Self {
+        let mut request = Self::new::<A>(CaptureKind::Axis, slot);
+        request.target.side = Some(side);
+        request
+    }
+
+    /// The slot that the capture will replace.
+    pub fn target(&self) -> &CaptureTarget {
+        &self.target
+    }
+
+    /// Sets the key that cancels the capture. `None` disables cancelling with a key.
+    pub fn with_cancel_key(mut self, key: Option<KeyCode>) -> Self {
+        self.cancel_key = key;
+        self
+    }
+
+    /// Excludes an input from being captured.
+    pub fn exclude(mut self, input: impl Into<InputSource>) -> Self {
+        self.excluded.push(input.into());
+        self
+    }
+
+    /// Ends the capture with [CaptureOutcome::TimedOut] if no input was captured within the given duration.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the absolute value a gamepad axis has to reach to be captured.
+    pub fn with_axis_threshold(mut self, threshold: f32) -> Self {
+        self.axis_threshold = threshold;
+        self
+    }
+}
+
+/// A resource for capturing the next input the player presses, e.g. for a rebinding menu. When the capture ends, a
+/// [BindingCaptured] message is written.
+///
+/// # Examples
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use press_here::{BindingCapture, BindingCaptured, CaptureRequest, Trigger};
+/// # use std::time::Duration;
+/// # struct Jump;
+/// fn start_rebinding(mut capture: ResMut<BindingCapture>) {
+///     capture.start(CaptureRequest::trigger::<Jump>(0).with_timeout(Duration::from_secs(5)));
+/// }
+///
+/// fn finish_rebinding(mut captured: MessageReader<BindingCaptured>, mut jump: ResMut<Trigger<Jump>>) {
+///     for captured in captured.read() {
+///         captured.apply_to_trigger(&mut jump);
+///     }
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct BindingCapture {
+    active: Option<ActiveCapture>,
+}
+
+struct ActiveCapture {
+    request: CaptureRequest,
+    started: Option<Duration>,
+    held_axes: Vec<(Entity, GamepadInput)>,
+}
+
+impl BindingCapture {
+    /// Starts capturing, replacing any capture in progress.
+    pub fn start(&mut self, request: CaptureRequest) {
+        self.active = Some(ActiveCapture {
+            request,
+            started: None,
+            held_axes: Vec::new(),
+        });
+    }
+
+    /// Stops the capture in progress without writing a message.
+    pub fn stop(&mut self) {
+        self.active = None;
+    }
+
+    /// Returns true if a capture is in progress.
+    pub fn is_capturing(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Returns the target of the capture in progress.
+    pub fn target(&self) -> Option<&CaptureTarget> {
+        self.active.as_ref().map(|active| &active.request.target)
+    }
+}
+
+/// An input captured by [BindingCapture].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CapturedInput {
+    pub source: InputSource,
+    /// The sign of the captured gamepad axis value. Always `1.0` for keys and buttons.
+    pub direction: f32,
+}
+
+impl CapturedInput {
+    /// Returns the captured input as a trigger binding, or `None` for gamepad axes.
+    pub fn trigger_binding(&self) -> Option<Box<dyn TriggerBinding>> {
+        match self.source {
+            InputSource::Key(key) => Some(Box::new(key)),
+            InputSource::MouseButton(button) => Some(Box::new(button)),
+            InputSource::GamepadButton(button) => Some(Box::new(button)),
+            InputSource::GamepadAxis(_) => None,
+        }
+    }
+
+    /// Returns the captured input as an axis binding. A gamepad axis that was pushed in the negative direction is
+    /// inverted, so that pushing it the same way produces positive values.
+    pub fn axis_binding(&self) -> Box<dyn AxisBinding> {
+        match self.source {
+            InputSource::Key(key) => Box::new(key),
+            InputSource::MouseButton(button) => Box::new(button),
+            InputSource::GamepadButton(button) => Box::new(button),
+            InputSource::GamepadAxis(axis) if self.direction < 0.0 => Box::new(Invert(axis)),
+            InputSource::GamepadAxis(axis) => Box::new(axis),
+        }
+    }
+}
+
+/// Returns a pair with one side of the given pair binding replaced.
+fn replace_side(
+    mut pair: Box<dyn AxisBinding>,
+    side: PairSide,
+    binding: Box<dyn AxisBinding>,
+) -> Box<dyn AxisBinding> {
+    let sides = pair
+        .children_mut()
+        .into_iter()
+        .filter_map(|child| match child {
+            BindingMut::Axis(child) => Some(child.clone_axis()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let Ok([negative, positive]) = <[_; 2]>::try_from(sides) else {
+        return binding;
+    };
+    match side {
+        PairSide::Negative => Box::new(Pair(binding, positive)),
+        PairSide::Positive => Box::new(Pair(negative, binding)),
+    }
+}
+
+/// How a capture ended.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CaptureOutcome {
+    Captured(CapturedInput),
+    Cancelled,
+    TimedOut,
+}
+
+/// A message written when a capture started with [BindingCapture] ends.
+#[derive(Message, Clone, Debug)]
+pub struct BindingCaptured {
+    pub target: CaptureTarget,
+    pub outcome: CaptureOutcome,
+}
+
+impl BindingCaptured {
+    /// Returns the captured input, if any.
+    pub fn input(&self) -> Option<CapturedInput> {
+        match self.outcome {
+            CaptureOutcome::Captured(input) => Some(input),
+            _ => None,
+        }
+    }
+
+    /// Replaces the targeted slot of the trigger with the captured input. Returns false if nothing was captured or the
+    /// capture targets a different action.
+    pub fn apply_to_trigger<T: 'static>(&self, trigger: &mut Trigger<T>) -> bool {
+        let binding = self.input().and_then(|input| input.trigger_binding());
+        match binding {
+            Some(binding) if self.target.is_trigger::<T>() => {
+                trigger.replace_slot(self.target.slot, binding);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Replaces the targeted slot of the axis with the captured input. Returns false if nothing was captured or the
+    /// capture targets a different action.
+    ///
+    /// If the slot is a [Pair], a captured key or button only replaces one side of it: the side of the request, or the
+    /// positive side if none was given. A captured gamepad axis replaces the whole pair unless a side was given.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{Axis, AxisBinding, BindingCaptured, CaptureOutcome, CaptureRequest, CapturedInput, Pair, PairSide};
+    /// # struct Walk;
+    /// let mut walk = Axis::<Walk>::new(Pair(KeyCode::KeyA, KeyCode::KeyD));
+    /// let captured = BindingCaptured {
+    ///     target: CaptureRequest::axis_side::<Walk>(0, PairSide::Negative).target().clone(),
+    ///     outcome: CaptureOutcome::Captured(CapturedInput {
+    ///         source: KeyCode::ArrowLeft.into(),
+    ///         direction: 1.0,
+    ///     }),
+    /// };
+    ///
+    /// assert!(captured.apply_to_axis(&mut walk));
+    /// assert_eq!(walk.binding().describe().to_string(), "Arrow Left / D");
+    /// ```
+    pub fn apply_to_axis<A: 'static>(&self, axis: &mut Axis<A>) -> bool {
+        let Some(input) = self.input().filter(|_| self.target.is_axis::<A>()) else {
+            return false;
+        };
+
+        let binding = input.axis_binding();
+        let slots = axis.binding().all_axes();
+        let side = match (self.target.side, input.source) {
+            (Some(side), _) => Some(side),
+            (None, InputSource::GamepadAxis(_)) => None,
+            (None, _) => Some(PairSide::Positive),
+        };
+
+        let replacement = match (slots.get(self.target.slot), side) {
+            (Some(slot), Some(side)) if slot.describe().kind == BindingKind::Pair => {
+                replace_side(slot.clone(), side, binding)
+            }
+            _ => binding,
+        };
+        axis.replace_slot(self.target.slot, replacement);
+        true
+    }
+}
+
+pub(crate) fn update_binding_capture(
+    mut capture: ResMut<BindingCapture>,
+    mut captured: MessageWriter<BindingCaptured>,
+    keycodes: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    time: Res<Time<Real>>,
+) {
+    let Some(active) = capture.active.as_mut() else {
+        return;
+    };
+    let request = &active.request;
+
+    let Some(started) = active.started else {
+        // Axes that are already pushed when the capture starts have to be released before they can be captured.
+        active.started = Some(time.elapsed());
+        active.held_axes = gamepads
+            .iter()
+            .flat_map(|(entity, pad)| {
+                pad.get_analog_axes()
+                    .filter(|input| pad.get(**input).unwrap_or(0.0).abs() >= request.axis_threshold)
+                    .map(move |input| (entity, *input))
+            })
+            .collect();
+        return;
+    };
+
+    let cancelled = request
+        .cancel_key
+        .is_some_and(|key| keycodes.just_pressed(key));
+    let timed_out = request
+        .timeout
+        .is_some_and(|timeout| time.elapsed() - started >= timeout);
+
+    let outcome = if cancelled {
+        Some(CaptureOutcome::Cancelled)
+    } else if let Some(input) = find_captured_input(active, &keycodes, &mouse_buttons, &gamepads) {
+        Some(CaptureOutcome::Captured(input))
+    } else if timed_out {
+        Some(CaptureOutcome::TimedOut)
+    } else {
+        None
+    };
+
+    if let Some(outcome) = outcome {
+        let active = capture.active.take().expect("capture should be active");
+        captured.write(BindingCaptured {
+            target: active.request.target,
+            outcome,
+        });
+    }
+}
+
+fn find_captured_input(
+    active: &mut ActiveCapture,
+    keycodes: &ButtonInput<KeyCode>,
+    mouse_buttons: &ButtonInput<MouseButton>,
+    gamepads: &Query<(Entity, &Gamepad)>,
+) -> Option<CapturedInput> {
+    let request = &active.request;
+    let allowed = |source: &InputSource| !request.excluded.contains(source);
+    let digital = |source: InputSource| CapturedInput {
+        source,
+        direction: 1.0,
+    };
+
+    let keys = keycodes
+        .get_just_pressed()
+        .map(|key| InputSource::Key(*key));
+    let mouse_buttons = mouse_buttons
+        .get_just_pressed()
+        .map(|button| InputSource::MouseButton(*button));
+    let gamepad_buttons = gamepads.iter().flat_map(|(_, pad)| {
+        pad.get_just_pressed()
+            .map(|button| InputSource::GamepadButton(*button))
+    });
+
+    if let Some(source) = keys
+        .chain(mouse_buttons)
+        .chain(gamepad_buttons)
+        .find(allowed)
+    {
+        return Some(digital(source));
+    }
+
+    if request.target.kind != CaptureKind::Axis {
+        return None;
+    }
+
+    active.held_axes.retain(|(entity, input)| {
+        gamepads
+            .get(*entity)
+            .ok()
+            .and_then(|(_, pad)| pad.get(*input))
+            .is_some_and(|value| value.abs() >= request.axis_threshold)
+    });
+
+    gamepads.iter().find_map(|(entity, pad)| {
+        pad.get_analog_axes().find_map(|input| {
+            let GamepadInput::Axis(axis) = input else {
+                return None;
+            };
+            let value = pad.get(*axis)?;
+            let source = InputSource::GamepadAxis(*axis);
+
+            let pushed = value.abs() >= request.axis_threshold;
+            let held = active.held_axes.contains(&(entity, *input));
+            (pushed && !held && allowed(&source)).then_some(CapturedInput {
+                source,
+                direction: value.signum(),
+            })
+        })
+    })
+}
@@ -0,0 +1,113 @@
+use bevy::{
+    ecs::{
+        resource::Resource,
+        system::{Res, ResMut},
+    },
+    platform::collections::HashMap,
+    time::{Fixed, Real, Time, Virtual},
+};
+use std::{any::TypeId, time::Duration};
+
+/// The clock that time-based bindings such as [Smooth](crate::Smooth) and [RateLimit](crate::RateLimit) read from.
+/// The clock can be set for the whole app with
+/// [PressHerePlugin::with_clock](crate::PressHerePlugin::with_clock) and overridden per action with e.g.
+/// [Axis::with_clock](crate::Axis::with_clock).
+///
+/// # Examples
+/// Smoothing that pauses and slows down with the game:
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, Axis, AxisBindingBuilder, InputClock};
+/// # struct Look;
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn(Axis::<Look>::new(GamepadAxis::RightStickX.smooth(0.1)).with_clock(InputClock::Virtual));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InputClock {
+    /// `Time<Real>`, which is not affected by pausing or time scaling.
+    #[default]
+    Real,
+    /// `Time<Virtual>`, which pauses and scales with the game.
+    Virtual,
+    /// `Time<Fixed>`, which advances by the fixed timestep. Use this when actions are updated in a fixed schedule.
+    Fixed,
+    /// A custom `Time<C>` clock registered with [AppExt::add_input_clock](crate::AppExt::add_input_clock). Create it
+    /// with [InputClock::custom].
+    Custom(TypeId),
+}
+
+impl InputClock {
+    /// Returns the clock reading from the `Time<C>` resource.
+    pub fn custom<C: 'static>() -> Self {
+        InputClock::Custom(TypeId::of::<C>())
+    }
+}
+
+/// A resource holding a copy of every clock that actions can read from, updated before the actions are.
+#[derive(Resource, Default)]
+pub struct InputClocks {
+    default: InputClock,
+    real: Time,
+    virtual_time: Time,
+    fixed: Time,
+    custom: HashMap<TypeId, Time>,
+}
+
+impl InputClocks {
+    pub(crate) fn new(default: InputClock) -> Self {
+        Self {
+            default,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn set_default_clock(&mut self, clock: InputClock) {
+        self.default = clock;
+    }
+
+    /// Advances every clock by the given duration.
+    pub(crate) fn advance_by(&mut self, delta: Duration) {
+        let clocks = [&mut self.real, &mut self.virtual_time, &mut self.fixed];
+        for time in clocks.into_iter().chain(self.custom.values_mut()) {
+            time.advance_by(delta);
+        }
+    }
+
+    /// The clock used by actions that don't set their own.
+    pub fn default_clock(&self) -> InputClock {
+        self.default
+    }
+
+    /// Returns the time of the given clock. Unregistered custom clocks fall back to `Time<Real>`.
+    pub fn get(&self, clock: InputClock) -> &Time {
+        match clock {
+            InputClock::Real => &self.real,
+            InputClock::Virtual => &self.virtual_time,
+            InputClock::Fixed => &self.fixed,
+            InputClock::Custom(id) => self.custom.get(&id).unwrap_or(&self.real),
+        }
+    }
+}
+
+pub(crate) fn update_input_clocks(
+    mut clocks: ResMut<InputClocks>,
+    real: Res<Time<Real>>,
+    virtual_time: Option<Res<Time<Virtual>>>,
+    fixed: Option<Res<Time<Fixed>>>,
+) {
+    clocks.real = real.as_generic();
+    if let Some(virtual_time) = virtual_time {
+        clocks.virtual_time = virtual_time.as_generic();
+    }
+    if let Some(fixed) = fixed {
+        clocks.fixed = fixed.as_generic();
+    }
+}
+
+pub(crate) fn update_custom_clock<C: Default + Send + Sync + 'static>(
+    mut clocks: ResMut<InputClocks>,
+    time: Res<Time<C>>,
+) {
+    clocks.custom.insert(TypeId::of::<C>(), time.as_generic());
+}
@@ -0,0 +1,82 @@
+use crate::{
+    axis::Axis,
+    axis2::Axis2,
+    context::{InputContext, InputContexts},
+    trigger::Trigger,
+};
+use bevy::{ecs::system::Res, math::Vec2};
+
+/// Returns a run condition that is true while the [`Trigger<T>`] resource is pressed. Like all conditions in this
+/// crate, it returns false if the resource doesn't exist.
+pub fn trigger_pressed<T: Send + Sync + 'static>()
+-> impl FnMut(Option<Res<Trigger<T>>>) -> bool + Clone {
+    |trigger| trigger.is_some_and(|trigger| trigger.pressed())
+}
+
+/// Returns a run condition that is true on the frame the [`Trigger<T>`] resource is pressed.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, trigger_just_pressed, testing::MockAppExt};
+/// let mut app = App::new();
+/// app.init_mock_inputs()
+///     .init_resource::<Jumps>()
+///     .add_trigger::<Jump>(KeyCode::Space)
+///     .add_systems(Update, jump.run_if(trigger_just_pressed::<Jump>()));
+///
+/// app.press(KeyCode::Space).update();
+/// app.update();
+/// assert_eq!(app.world().resource::<Jumps>().0, 1);
+///
+/// fn jump(mut jumps: ResMut<Jumps>) {
+///     jumps.0 += 1;
+/// }
+///
+/// # struct Jump;
+/// #[derive(Resource, Default)]
+/// struct Jumps(u32);
+/// ```
+pub fn trigger_just_pressed<T: Send + Sync + 'static>()
+-> impl FnMut(Option<Res<Trigger<T>>>) -> bool + Clone {
+    |trigger| trigger.is_some_and(|trigger| trigger.just_pressed())
+}
+
+/// Returns a run condition that is true on the frame the [`Trigger<T>`] resource is released.
+pub fn trigger_just_released<T: Send + Sync + 'static>()
+-> impl FnMut(Option<Res<Trigger<T>>>) -> bool + Clone {
+    |trigger| trigger.is_some_and(|trigger| trigger.just_released())
+}
+
+/// Returns a run condition that is true while the value of the [`Axis<A>`] resource is not zero.
+pub fn axis_nonzero<A: Send + Sync + 'static>() -> impl FnMut(Option<Res<Axis<A>>>) -> bool + Clone
+{
+    |axis| axis.is_some_and(|axis| axis.value() != 0.0)
+}
+
+/// Returns a run condition that is true while the value of the [`Axis<A>`] resource is greater than `threshold`.
+pub fn axis_above<A: Send + Sync + 'static>(
+    threshold: f32,
+) -> impl FnMut(Option<Res<Axis<A>>>) -> bool + Clone {
+    move |axis| axis.is_some_and(|axis| axis.value() > threshold)
+}
+
+/// Returns a run condition that is true while the value of the [`Axis<A>`] resource is less than `threshold`.
+pub fn axis_below<A: Send + Sync + 'static>(
+    threshold: f32,
+) -> impl FnMut(Option<Res<Axis<A>>>) -> bool + Clone {
+    move |axis| axis.is_some_and(|axis| axis.value() < threshold)
+}
+
+/// Returns a run condition that is true while the value of the [`Axis2<A>`] resource is not zero.
+pub fn axis2_nonzero<A: Send + Sync + 'static>() -> impl FnMut(Option<Res<Axis2<A>>>) -> bool + Clone
+{
+    |axis| axis.is_some_and(|axis| axis.value() != Vec2::ZERO)
+}
+
+/// Returns a run condition that is true while the context is active, see [InputContexts].
+pub fn input_context_active(
+    context: InputContext,
+) -> impl FnMut(Option<Res<InputContexts>>) -> bool + Clone {
+    move |contexts| contexts.is_some_and(|contexts| contexts.is_active(context))
+}
@@ -0,0 +1,178 @@
+use crate::{
+    context::InputContext,
+    describe::{BindingDescription, BindingKind},
+    inputs::InputSource,
+    registry::ActionRegistry,
+};
+use bevy::{ecs::world::World, log::warn, platform::collections::HashSet};
+use std::fmt::{self, Display};
+
+/// Two registered actions that can be activated by the same inputs, e.g. after the player rebinds `Jump` to a key that
+/// is already used by `Interact`.
+///
+/// Bindings are split into their alternatives, e.g. the two keys of `(KeyCode::Space, KeyCode::Enter)`, the two sides
+/// of a [Pair](crate::Pair), or the steps of a [Sequence](crate::Sequence). Two actions conflict if an alternative of
+/// one needs a subset of the inputs of an alternative of the other, so both `Space` and `Space` conflict, and so do `S`
+/// and `Ctrl + S`. Negated inputs like the key of `KeyCode::Space.not()` aren't needed, so they never conflict. Actions
+/// only conflict if they can be active at the same time, i.e. if they're in the same [InputContext] or one of them has
+/// no context.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, BindingConflict, InputContext, Pair, TriggerBindingBuilder};
+/// let mut app = App::new();
+/// app.add_trigger::<Jump>((KeyCode::Space, GamepadButton::South))
+///     .add_trigger::<Idle>(KeyCode::Space.not())
+///     .add_trigger::<Interact>(KeyCode::KeyE)
+///     .add_trigger::<Confirm>(KeyCode::KeyE)
+///     .set_action_context::<Confirm>(InputContext::of::<Menu>())
+///     .add_trigger::<Dodge>(GamepadButton::South)
+///     .add_axis::<Walk>(Pair(KeyCode::KeyA, KeyCode::KeyD))
+///     .add_axis::<Lean>(Pair(KeyCode::KeyA, KeyCode::KeyQ));
+///
+/// let conflicts = BindingConflict::find_all(app.world());
+/// assert_eq!(conflicts.len(), 3);
+/// assert_eq!(conflicts[0].to_string(), "Jump and Dodge are both bound to South");
+/// assert_eq!(conflicts[1].to_string(), "Interact and Confirm are both bound to E in Menu");
+/// assert_eq!(conflicts[2].to_string(), "Walk and Lean are both bound to A");
+///
+/// # struct Jump;
+/// # struct Idle;
+/// # struct Interact;
+/// # struct Confirm;
+/// # struct Dodge;
+/// # struct Menu;
+/// # struct Walk;
+/// # struct Lean;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BindingConflict {
+    /// The short type names of the two actions.
+    pub actions: [String; 2],
+    /// The inputs that activate both actions.
+    pub inputs: Vec<InputSource>,
+    /// The context that both actions are active in, or `None` if neither has a context.
+    pub context: Option<InputContext>,
+}
+
+impl BindingConflict {
+    /// Finds the conflicts between the bindings of all axis and trigger resources added with
+    /// [AppExt](crate::AppExt).
+    pub fn find_all(world: &World) -> Vec<Self> {
+        let Some(registry) = world.get_resource::<ActionRegistry>() else {
+            return Vec::new();
+        };
+
+        let actions = registry
+            .actions()
+            .filter_map(|action| {
+                let state = (action.state)(world)?;
+                let description = (action.describe)(world)?;
+                Some((action.name, state.context, alternatives(&description)))
+            })
+            .collect::<Vec<_>>();
+
+        let mut conflicts = Vec::new();
+        for (index, (name, context, alternatives)) in actions.iter().enumerate() {
+            for (other_name, other_context, other_alternatives) in &actions[index + 1..] {
+                if context.is_some() && other_context.is_some() && context != other_context {
+                    continue;
+                }
+
+                let shared = alternatives.iter().find_map(|inputs| {
+                    other_alternatives
+                        .iter()
+                        .find_map(|other| overlap(inputs, other))
+                });
+
+                if let Some(inputs) = shared {
+                    conflicts.push(BindingConflict {
+                        actions: [name.to_string(), other_name.to_string()],
+                        inputs,
+                        context: context.or(*other_context),
+                    });
+                }
+            }
+        }
+
+        conflicts
+    }
+}
+
+impl Display for BindingConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        write!(
+            f,
+            "{} and {} are both bound to {}",
+            self.actions[0],
+            self.actions[1],
+            inputs.join(" + ")
+        )?;
+
+        match &self.context {
+            Some(context) => write!(f, " in {context}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Splits a description into the input sets of its alternatives. Children that are needed together, e.g. the keys of
+/// a chord, are combined into one set per combination of their alternatives.
+fn alternatives(description: &BindingDescription) -> Vec<Vec<InputSource>> {
+    match &description.kind {
+        BindingKind::Input(input) => vec![vec![*input]],
+        // Either side of a pair or a modifier key is used on its own.
+        BindingKind::Any | BindingKind::Pair | BindingKind::Dual | BindingKind::Named(_) => {
+            description.children.iter().flat_map(alternatives).collect()
+        }
+        // A negated child must not be pressed, so none of its inputs are needed.
+        BindingKind::Prefix(prefix) if prefix == "not" => Vec::new(),
+        // Each step of a sequence is pressed on its own.
+        BindingKind::Sequence => description
+            .inputs()
+            .into_iter()
+            .map(|input| vec![input])
+            .collect(),
+        _ => {
+            let mut combined = vec![Vec::new()];
+            for child in description.children.iter().map(alternatives) {
+                if child.is_empty() {
+                    continue;
+                }
+                combined = combined
+                    .iter()
+                    .flat_map(|inputs| {
+                        child
+                            .iter()
+                            .map(move |other| [inputs.as_slice(), other].concat())
+                    })
+                    .collect();
+            }
+            combined.retain(|inputs| !inputs.is_empty());
+            combined
+        }
+    }
+}
+
+/// Returns the smaller of the two input sets if it's contained in the other one.
+fn overlap(a: &[InputSource], b: &[InputSource]) -> Option<Vec<InputSource>> {
+    let (smaller, larger) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let larger = larger.iter().collect::<HashSet<_>>();
+    smaller
+        .iter()
+        .all(|input| larger.contains(input))
+        .then(|| smaller.to_vec())
+}
+
+pub(crate) fn warn_binding_conflicts(world: &World) {
+    for conflict in BindingConflict::find_all(world) {
+        warn!("Binding conflict: {conflict}");
+    }
+}
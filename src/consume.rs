@@ -0,0 +1,138 @@
+use crate::{
+    inputs::InputSource,
+    latch::LatchedInputs,
+    registry::{ActionRegistry, ActionState},
+};
+use bevy::{
+    ecs::entity::Entity,
+    ecs::world::World,
+    input::{
+        ButtonInput,
+        gamepad::{Gamepad, GamepadInput},
+        keyboard::KeyCode,
+        mouse::MouseButton,
+    },
+    platform::collections::HashSet,
+};
+use std::hash::Hash;
+
+/// An active action and the held inputs of its binding.
+struct Claim {
+    priority: i32,
+    inputs: HashSet<InputSource>,
+    suppress: fn(&mut World),
+}
+
+impl Claim {
+    /// Returns true if this claim takes precedence over the other one. Claims must share an input, and either have a
+    /// higher priority, or the same priority and a strict superset of the inputs, like Ctrl+S over S.
+    fn beats(&self, other: &Claim) -> bool {
+        if self.inputs.is_disjoint(&other.inputs) {
+            return false;
+        }
+
+        self.priority > other.priority
+            || (self.priority == other.priority
+                && self.inputs.len() > other.inputs.len()
+                && self.inputs.is_superset(&other.inputs))
+    }
+}
+
+/// Returns the buttons that are held or were pressed this frame, so that a press and release within one frame is
+/// included.
+fn active_buttons<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    buttons: &ButtonInput<T>,
+) -> impl Iterator<Item = T> + '_ {
+    buttons
+        .get_pressed()
+        .chain(buttons.get_just_pressed())
+        .copied()
+}
+
+/// Returns every button and axis that is held or was pressed this frame. If input latching is enabled, the latched
+/// buttons are used, like when the actions were updated.
+fn held_inputs(world: &mut World) -> HashSet<InputSource> {
+    let mut held = HashSet::new();
+    let mut gamepads = world.query::<(Entity, &Gamepad)>();
+    let world = &*world;
+    let latched = world.get_resource::<LatchedInputs>();
+
+    let keycodes = match latched {
+        Some(latched) => Some(&latched.keycodes),
+        None => world.get_resource::<ButtonInput<KeyCode>>(),
+    };
+    if let Some(keycodes) = keycodes {
+        held.extend(active_buttons(keycodes).map(InputSource::Key));
+    }
+
+    let mouse_buttons = match latched {
+        Some(latched) => Some(&latched.mouse_buttons),
+        None => world.get_resource::<ButtonInput<MouseButton>>(),
+    };
+    if let Some(buttons) = mouse_buttons {
+        held.extend(active_buttons(buttons).map(InputSource::MouseButton));
+    }
+
+    for (entity, gamepad) in gamepads.iter(world) {
+        let buttons = latched
+            .and_then(|latched| latched.gamepads.get(&entity))
+            .unwrap_or(gamepad.digital());
+        held.extend(active_buttons(buttons).map(InputSource::GamepadButton));
+        held.extend(
+            gamepad
+                .analog()
+                .all_axes_and_values()
+                .filter(|(_, value)| *value != 0.0)
+                .filter_map(|(input, _)| match input {
+                    GamepadInput::Axis(axis) => Some(InputSource::GamepadAxis(*axis)),
+                    _ => None,
+                }),
+        );
+    }
+
+    held
+}
+
+/// Suppresses every active action whose held inputs are claimed by an action that takes precedence, see
+/// [PressHerePlugin::with_input_consumption](crate::PressHerePlugin::with_input_consumption).
+pub(crate) fn consume_inputs(world: &mut World) {
+    let held = held_inputs(world);
+    let Some(registry) = world.get_resource::<ActionRegistry>() else {
+        return;
+    };
+
+    let mut all = Vec::new();
+    for action in registry.actions() {
+        let Some(ActionState {
+            active: true,
+            priority,
+            ..
+        }) = (action.state)(world)
+        else {
+            continue;
+        };
+        let Some(description) = (action.describe)(world) else {
+            continue;
+        };
+
+        all.push(Claim {
+            priority,
+            inputs: description
+                .inputs()
+                .into_iter()
+                .filter(|input| held.contains(input))
+                .collect(),
+            suppress: action.suppress,
+        });
+    }
+
+    let suppressed = all
+        .iter()
+        .filter(|claim| all.iter().any(|other| other.beats(claim)))
+        .map(|claim| claim.suppress)
+        .collect::<Vec<_>>();
+
+    for suppress in suppressed {
+        suppress(world);
+    }
+}
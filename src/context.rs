@@ -0,0 +1,134 @@
+use bevy::{
+    ecs::{resource::Resource, system::ResMut},
+    platform::collections::HashSet,
+    utils::prelude::ShortName,
+};
+use std::{
+    any::{TypeId, type_name},
+    fmt::{self, Display},
+};
+
+/// A group of actions that can be enabled and disabled together, e.g. gameplay, menu, or vehicle controls. Contexts are
+/// identified by a marker type, just like actions. Assign an action to a context with e.g.
+/// [Trigger::with_context](crate::Trigger::with_context) or
+/// [AppExt::set_action_context](crate::AppExt::set_action_context), and control which contexts are active with the
+/// [InputContexts] resource.
+///
+/// Actions in an inactive context are not evaluated and report zero or unpressed. Actions without a context are always
+/// active.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InputContext {
+    type_id: TypeId,
+    name: &'static str,
+}
+
+impl InputContext {
+    /// Returns the context identified by the marker type `C`.
+    pub fn of<C: 'static>() -> Self {
+        Self {
+            type_id: TypeId::of::<C>(),
+            name: type_name::<C>(),
+        }
+    }
+
+    /// The short type name of the marker type.
+    pub fn name(&self) -> String {
+        ShortName(self.name).to_string()
+    }
+}
+
+impl Display for InputContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", ShortName(self.name))
+    }
+}
+
+/// A resource that controls which [InputContext]s are active. A context is active if it's on top of the stack, or if
+/// it has been enabled. Use the stack for modal layers like menus on top of gameplay, and [enable](Self::enable) for
+/// contexts that should stay active regardless of the stack.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, InputContext, InputContexts, Trigger, testing::MockAppExt};
+/// let mut app = App::new();
+/// app.init_mock_inputs()
+///     .add_trigger::<Jump>(KeyCode::Space)
+///     .set_action_context::<Jump>(InputContext::of::<Gameplay>());
+///
+/// app.world_mut().resource_mut::<InputContexts>().push(InputContext::of::<Gameplay>());
+/// app.world_mut().resource_mut::<InputContexts>().push(InputContext::of::<Menu>());
+///
+/// app.press(KeyCode::Space).update();
+/// assert!(!app.world().resource::<Trigger<Jump>>().pressed());
+///
+/// // The key is still held, so Jump is pressed as soon as its context is active again.
+/// app.world_mut().resource_mut::<InputContexts>().pop();
+/// app.update();
+/// assert!(app.world().resource::<Trigger<Jump>>().just_pressed());
+///
+/// # struct Jump;
+/// # struct Gameplay;
+/// # struct Menu;
+/// ```
+#[derive(Resource, Default)]
+pub struct InputContexts {
+    stack: Vec<InputContext>,
+    enabled: HashSet<InputContext>,
+}
+
+impl InputContexts {
+    /// Pushes a context on top of the stack, deactivating the previous top unless it's enabled.
+    pub fn push(&mut self, context: InputContext) {
+        self.stack.push(context);
+    }
+
+    /// Removes the top context from the stack and returns it.
+    pub fn pop(&mut self) -> Option<InputContext> {
+        self.stack.pop()
+    }
+
+    /// The context on top of the stack.
+    pub fn top(&self) -> Option<InputContext> {
+        self.stack.last().copied()
+    }
+
+    /// The contexts on the stack, from bottom to top.
+    pub fn stack(&self) -> &[InputContext] {
+        &self.stack
+    }
+
+    /// Keeps the context active regardless of the stack.
+    pub fn enable(&mut self, context: InputContext) {
+        self.enabled.insert(context);
+    }
+
+    pub fn disable(&mut self, context: InputContext) {
+        self.enabled.remove(&context);
+    }
+
+    /// Enables the context if it's disabled, and disables it otherwise.
+    pub fn toggle(&mut self, context: InputContext) {
+        if !self.enabled.remove(&context) {
+            self.enabled.insert(context);
+        }
+    }
+
+    /// Returns true if the context is on top of the stack or enabled.
+    pub fn is_active(&self, context: InputContext) -> bool {
+        self.top() == Some(context) || self.enabled.contains(&context)
+    }
+
+    /// Returns true if an action with the given context should be evaluated.
+    pub(crate) fn allows(&self, context: Option<InputContext>) -> bool {
+        context.is_none_or(|context| self.is_active(context))
+    }
+}
+
+pub(crate) fn enable_context(context: InputContext) -> impl FnMut(ResMut<InputContexts>) {
+    move |mut contexts| contexts.enable(context)
+}
+
+pub(crate) fn disable_context(context: InputContext) -> impl FnMut(ResMut<InputContexts>) {
+    move |mut contexts| contexts.disable(context)
+}
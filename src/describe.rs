@@ -0,0 +1,334 @@
+use crate::{
+    axis::AxisBinding, axis2::DualAxisBinding, inputs::InputSource, trigger::TriggerBinding,
+};
+use bevy::input::{
+    gamepad::{GamepadAxis, GamepadButton},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+};
+use std::fmt::{self, Display};
+
+/// How a [BindingDescription] relates to its children. This determines how the description is displayed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BindingKind {
+    /// A physical input, e.g. a key or a gamepad axis.
+    Input(InputSource),
+    /// A constant value.
+    Constant(f32),
+    /// A leaf binding without children, e.g. mouse movement. Displayed as its name.
+    Named(String),
+    /// Active if any of the children is active. Displayed as `A or B`.
+    Any,
+    /// A negative and a positive child. Displayed as `A / D`.
+    Pair,
+    /// Active if all of the children are active. Displayed as `Ctrl + S`.
+    All,
+    /// An X and a Y child of a two-dimensional binding. Displayed as `A / D, S / W`.
+    Dual,
+    /// Children that must be activated in order. Displayed as `Down, then Right`.
+    Sequence,
+    /// Modifies the first child. Displayed as `A (name B, C, parameters)`, where `B` and `C` are the remaining children.
+    Modifier(String),
+    /// Combines two children with an operator. Displayed as `A * B`.
+    Operator(String),
+    /// Modifies a single child. Displayed as `not A`.
+    Prefix(String),
+}
+
+/// A structured description of a binding, returned by [AxisBinding::describe] and [TriggerBinding::describe]. It can
+/// be walked to inspect the binding tree, or displayed as a human-readable string.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AxisBinding, AxisBindingBuilder, Pair};
+/// let binding = (
+///     Pair(KeyCode::KeyA, KeyCode::KeyD),
+///     GamepadAxis::LeftStickX.deadzone(0.1),
+/// );
+///
+/// assert_eq!(
+///     binding.describe().to_string(),
+///     "A / D or Left Stick X (deadzone 0.1)"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BindingDescription {
+    pub kind: BindingKind,
+    /// Named numeric parameters of the binding, e.g. `("threshold", 0.1)` for a deadzone.
+    pub parameters: Vec<(&'static str, f32)>,
+    pub children: Vec<BindingDescription>,
+}
+
+impl BindingDescription {
+    pub fn new(kind: BindingKind) -> Self {
+        Self {
+            kind,
+            parameters: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// A description of a physical input.
+    pub fn input(source: impl Into<InputSource>) -> Self {
+        Self::new(BindingKind::Input(source.into()))
+    }
+
+    /// A description of a leaf binding without children.
+    pub fn named(name: impl Into<String>) -> Self {
+        Self::new(BindingKind::Named(name.into()))
+    }
+
+    /// A description of a modifier with the given children.
+    pub fn modifier(name: impl Into<String>, children: Vec<BindingDescription>) -> Self {
+        Self::new(BindingKind::Modifier(name.into())).with_children(children)
+    }
+
+    pub fn with_children(mut self, children: Vec<BindingDescription>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn with_parameter(mut self, name: &'static str, value: f32) -> Self {
+        self.parameters.push((name, value));
+        self
+    }
+
+    /// Returns the value of the parameter with the given name.
+    pub fn parameter(&self, name: &str) -> Option<f32> {
+        self.parameters
+            .iter()
+            .find_map(|(key, value)| (*key == name).then_some(*value))
+    }
+
+    /// Returns all physical inputs in this description and its children, depth first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AxisBinding, InputSource, Pair};
+    /// let binding = (Pair(KeyCode::KeyA, KeyCode::KeyD), GamepadAxis::LeftStickX);
+    ///
+    /// assert_eq!(
+    ///     binding.describe().inputs(),
+    ///     vec![
+    ///         InputSource::Key(KeyCode::KeyA),
+    ///         InputSource::Key(KeyCode::KeyD),
+    ///         InputSource::GamepadAxis(GamepadAxis::LeftStickX),
+    ///     ]
+    /// );
+    /// ```
+    pub fn inputs(&self) -> Vec<InputSource> {
+        let mut inputs = Vec::new();
+        self.collect_inputs(&mut inputs);
+        inputs
+    }
+
+    fn collect_inputs(&self, inputs: &mut Vec<InputSource>) {
+        if let BindingKind::Input(source) = self.kind {
+            inputs.push(source);
+        }
+
+        for child in &self.children {
+            child.collect_inputs(inputs);
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(
+            self.kind,
+            BindingKind::Any
+                | BindingKind::Pair
+                | BindingKind::All
+                | BindingKind::Dual
+                | BindingKind::Sequence
+                | BindingKind::Operator(_)
+        )
+    }
+
+    fn fmt_child(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_compound() {
+            write!(f, "({self})")
+        } else {
+            write!(f, "{self}")
+        }
+    }
+
+    fn fmt_joined(&self, f: &mut fmt::Formatter<'_>, separator: &str) -> fmt::Result {
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
+
+            if matches!(self.kind, BindingKind::Any) {
+                write!(f, "{child}")?;
+            } else {
+                child.fmt_child(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for BindingDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            BindingKind::Input(source) => write!(f, "{source}"),
+            BindingKind::Constant(value) => write!(f, "{value}"),
+            BindingKind::Named(name) => f.write_str(name),
+            BindingKind::Any => self.fmt_joined(f, " or "),
+            BindingKind::Pair => self.fmt_joined(f, " / "),
+            BindingKind::All => self.fmt_joined(f, " + "),
+            BindingKind::Dual => self.fmt_joined(f, ", "),
+            BindingKind::Sequence => self.fmt_joined(f, ", then "),
+            BindingKind::Operator(operator) => self.fmt_joined(f, &format!(" {operator} ")),
+            BindingKind::Prefix(prefix) => {
+                write!(f, "{prefix} ")?;
+                self.fmt_joined(f, ", ")
+            }
+            BindingKind::Modifier(name) => {
+                let Some((first, rest)) = self.children.split_first() else {
+                    return f.write_str(name);
+                };
+
+                let details = rest
+                    .iter()
+                    .map(ToString::to_string)
+                    .chain(self.parameters.iter().map(|(_, value)| value.to_string()))
+                    .collect::<Vec<_>>();
+
+                if details.is_empty() {
+                    write!(f, "{first} ({name})")
+                } else {
+                    write!(f, "{first} ({name} {})", details.join(", "))
+                }
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            InputSource::Key(key) => {
+                let name = format!("{key:?}");
+                let name = name
+                    .strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .unwrap_or(&name);
+                split_words(name)
+            }
+            InputSource::MouseButton(button) => {
+                format!("Mouse {}", split_words(&format!("{button:?}")))
+            }
+            InputSource::GamepadButton(button) => split_words(&format!("{button:?}")),
+            InputSource::GamepadAxis(axis) => split_words(&format!("{axis:?}")),
+        };
+
+        f.write_str(&name)
+    }
+}
+
+/// Splits a `PascalCase` name into space separated words, e.g. `LeftStickX` becomes `Left Stick X`.
+fn split_words(name: &str) -> String {
+    let mut words = String::with_capacity(name.len());
+    for (i, c) in name.char_indices() {
+        if i > 0 && c.is_uppercase() {
+            words.push(' ');
+        }
+        words.push(c);
+    }
+    words
+}
+
+/// A mutable reference to a child binding, returned by [AxisBinding::children_mut], [TriggerBinding::children_mut],
+/// and [DualAxisBinding::children_mut].
+pub enum BindingMut<'a> {
+    Axis(&'a mut dyn AxisBinding),
+    Trigger(&'a mut dyn TriggerBinding),
+    DualAxis(&'a mut dyn DualAxisBinding),
+}
+
+impl BindingMut<'_> {
+    /// Resets the referenced binding, see [TriggerBinding::reset].
+    pub fn reset(self) {
+        match self {
+            BindingMut::Axis(binding) => binding.reset(),
+            BindingMut::Trigger(binding) => binding.reset(),
+            BindingMut::DualAxis(binding) => binding.reset(),
+        }
+    }
+
+    /// Calls `f` for every physical input in this binding and its children, depth first. This allows replacing inputs
+    /// anywhere in a binding tree without downcasting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AxisBinding, BindingMut, InputSource, Pair};
+    /// let mut binding = (Pair(KeyCode::KeyA, KeyCode::KeyD), GamepadAxis::LeftStickX);
+    ///
+    /// BindingMut::Axis(&mut binding).for_each_input(&mut |mut input| {
+    ///     if input.source() == InputSource::Key(KeyCode::KeyA) {
+    ///         input.set(KeyCode::ArrowLeft);
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(binding.0.0, KeyCode::ArrowLeft);
+    /// ```
+    pub fn for_each_input(&mut self, f: &mut dyn FnMut(InputMut<'_>)) {
+        let children = match self {
+            BindingMut::Axis(binding) => {
+                if let Some(input) = binding.input_mut() {
+                    return f(input);
+                }
+                binding.children_mut()
+            }
+            BindingMut::Trigger(binding) => {
+                if let Some(input) = binding.input_mut() {
+                    return f(input);
+                }
+                binding.children_mut()
+            }
+            BindingMut::DualAxis(binding) => binding.children_mut(),
+        };
+
+        for mut child in children {
+            child.for_each_input(f);
+        }
+    }
+}
+
+/// A mutable reference to a physical input in a binding, returned by [AxisBinding::input_mut] and
+/// [TriggerBinding::input_mut].
+pub enum InputMut<'a> {
+    Key(&'a mut KeyCode),
+    MouseButton(&'a mut MouseButton),
+    GamepadButton(&'a mut GamepadButton),
+    GamepadAxis(&'a mut GamepadAxis),
+}
+
+impl InputMut<'_> {
+    /// Returns the current input.
+    pub fn source(&self) -> InputSource {
+        match self {
+            InputMut::Key(key) => InputSource::Key(**key),
+            InputMut::MouseButton(button) => InputSource::MouseButton(**button),
+            InputMut::GamepadButton(button) => InputSource::GamepadButton(**button),
+            InputMut::GamepadAxis(axis) => InputSource::GamepadAxis(**axis),
+        }
+    }
+
+    /// Replaces the input. Only inputs of the same kind can be set, e.g. a key can only be replaced by another key.
+    /// Returns false if the kinds don't match.
+    pub fn set(&mut self, source: impl Into<InputSource>) -> bool {
+        match (self, source.into()) {
+            (InputMut::Key(key), InputSource::Key(new)) => **key = new,
+            (InputMut::MouseButton(button), InputSource::MouseButton(new)) => **button = new,
+            (InputMut::GamepadButton(button), InputSource::GamepadButton(new)) => **button = new,
+            (InputMut::GamepadAxis(axis), InputSource::GamepadAxis(new)) => **axis = new,
+            _ => return false,
+        }
+        true
+    }
+}
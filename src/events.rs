@@ -0,0 +1,196 @@
+use crate::{axis::Axis, axis2::Axis2, trigger::Trigger};
+use bevy::{
+    ecs::{
+        entity::Entity,
+        event::Event,
+        message::{Message, MessageWriter},
+        system::{Commands, Query, Res},
+    },
+    math::Vec2,
+};
+use std::marker::PhantomData;
+
+/// Fired when a [`Trigger<T>`] is pressed. Enabled with [AppExt::add_trigger_events](crate::AppExt::add_trigger_events).
+///
+/// The event is both triggered for observers and written as a message. `entity` is `None` for the trigger resource and
+/// holds the entity for [`Trigger<T>`] components.
+///
+/// # Examples
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, TriggerPressed};
+/// App::new()
+///     .add_trigger::<Jump>(KeyCode::Space)
+///     .add_trigger_events::<Jump>()
+///     .add_observer(|_: On<TriggerPressed<Jump>>| {
+///         // ...
+///     });
+///
+/// # struct Jump;
+/// ```
+#[derive(Event, Message)]
+pub struct TriggerPressed<T: Send + Sync + 'static> {
+    pub entity: Option<Entity>,
+    trigger: PhantomData<T>,
+}
+
+/// Fired when a [`Trigger<T>`] is released. Enabled with
+/// [AppExt::add_trigger_events](crate::AppExt::add_trigger_events).
+///
+/// The event is both triggered for observers and written as a message. `entity` is `None` for the trigger resource and
+/// holds the entity for [`Trigger<T>`] components.
+#[derive(Event, Message)]
+pub struct TriggerReleased<T: Send + Sync + 'static> {
+    pub entity: Option<Entity>,
+    trigger: PhantomData<T>,
+}
+
+/// Fired when the value of an [`Axis<A>`] changes. Enabled with [AppExt::add_axis_events](crate::AppExt::add_axis_events).
+///
+/// The event is both triggered for observers and written as a message. `entity` is `None` for the axis resource and
+/// holds the entity for [`Axis<A>`] components.
+///
+/// # Examples
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, AxisChanged, Pair};
+/// App::new()
+///     .add_axis::<Walk>(Pair(KeyCode::KeyA, KeyCode::KeyD))
+///     .add_axis_events::<Walk>()
+///     .add_systems(Update, walk);
+///
+/// fn walk(mut changes: MessageReader<AxisChanged<Walk>>) {
+///     for change in changes.read() {
+///         info!("Walk changed from {} to {}", change.previous, change.value);
+///     }
+/// }
+///
+/// # struct Walk;
+/// ```
+#[derive(Event, Message)]
+pub struct AxisChanged<A: Send + Sync + 'static> {
+    pub entity: Option<Entity>,
+    pub value: f32,
+    pub previous: f32,
+    axis: PhantomData<A>,
+}
+
+/// Fired when the value of an [`Axis2<A>`] changes. Enabled with
+/// [AppExt::add_axis2_events](crate::AppExt::add_axis2_events).
+///
+/// The event is both triggered for observers and written as a message. `entity` is `None` for the axis resource and
+/// holds the entity for [`Axis2<A>`] components.
+#[derive(Event, Message)]
+pub struct Axis2Changed<A: Send + Sync + 'static> {
+    pub entity: Option<Entity>,
+    pub value: Vec2,
+    pub previous: Vec2,
+    axis: PhantomData<A>,
+}
+
+impl<T: Send + Sync + 'static> TriggerPressed<T> {
+    pub fn new(entity: Option<Entity>) -> Self {
+        Self {
+            entity,
+            trigger: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> TriggerReleased<T> {
+    pub fn new(entity: Option<Entity>) -> Self {
+        Self {
+            entity,
+            trigger: PhantomData,
+        }
+    }
+}
+
+impl<A: Send + Sync + 'static> AxisChanged<A> {
+    pub fn new(entity: Option<Entity>, value: f32, previous: f32) -> Self {
+        Self {
+            entity,
+            value,
+            previous,
+            axis: PhantomData,
+        }
+    }
+}
+
+impl<A: Send + Sync + 'static> Axis2Changed<A> {
+    pub fn new(entity: Option<Entity>, value: Vec2, previous: Vec2) -> Self {
+        Self {
+            entity,
+            value,
+            previous,
+            axis: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn emit_trigger_events<T: Send + Sync + 'static>(
+    mut commands: Commands,
+    resource: Option<Res<Trigger<T>>>,
+    components: Query<(Entity, &Trigger<T>)>,
+    mut pressed: MessageWriter<TriggerPressed<T>>,
+    mut released: MessageWriter<TriggerReleased<T>>,
+) {
+    let resource = resource.as_deref().map(|trigger| (None, trigger));
+    let components = components
+        .iter()
+        .map(|(entity, trigger)| (Some(entity), trigger));
+
+    for (entity, trigger) in resource.into_iter().chain(components) {
+        if trigger.just_pressed() {
+            pressed.write(TriggerPressed::new(entity));
+            commands.trigger(TriggerPressed::<T>::new(entity));
+        }
+
+        if trigger.just_released() {
+            released.write(TriggerReleased::new(entity));
+            commands.trigger(TriggerReleased::<T>::new(entity));
+        }
+    }
+}
+
+pub(crate) fn emit_axis_events<A: Send + Sync + 'static>(
+    mut commands: Commands,
+    resource: Option<Res<Axis<A>>>,
+    components: Query<(Entity, &Axis<A>)>,
+    mut changed: MessageWriter<AxisChanged<A>>,
+) {
+    let resource = resource.as_deref().map(|axis| (None, axis));
+    let components = components.iter().map(|(entity, axis)| (Some(entity), axis));
+
+    for (entity, axis) in resource.into_iter().chain(components) {
+        if axis.value != axis.previous_value {
+            changed.write(AxisChanged::new(entity, axis.value, axis.previous_value));
+            commands.trigger(AxisChanged::<A>::new(
+                entity,
+                axis.value,
+                axis.previous_value,
+            ));
+        }
+    }
+}
+
+pub(crate) fn emit_axis2_events<A: Send + Sync + 'static>(
+    mut commands: Commands,
+    resource: Option<Res<Axis2<A>>>,
+    components: Query<(Entity, &Axis2<A>)>,
+    mut changed: MessageWriter<Axis2Changed<A>>,
+) {
+    let resource = resource.as_deref().map(|axis| (None, axis));
+    let components = components.iter().map(|(entity, axis)| (Some(entity), axis));
+
+    for (entity, axis) in resource.into_iter().chain(components) {
+        if axis.value != axis.previous_value {
+            changed.write(Axis2Changed::new(entity, axis.value, axis.previous_value));
+            commands.trigger(Axis2Changed::<A>::new(
+                entity,
+                axis.value,
+                axis.previous_value,
+            ));
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    axis::{AxisBinding, data::AxisBindingData},
+    describe::{BindingDescription, BindingMut},
+    inputs::Inputs,
+    trigger::{TriggerBinding, TriggerState, data::TriggerBindingData},
+};
+use bevy::{
+    ecs::{entity::Entity, message::MessageReader, resource::Resource, system::ResMut},
+    input::{ButtonState, gamepad::GamepadEvent},
+};
+
+/// The minimum absolute axis value needed for an axis change to count as gamepad usage.
+const AXIS_USAGE_THRESHOLD: f32 = 0.5;
+
+/// Determines which gamepads a binding reads from.
+///
+/// # Examples
+/// Scoping a trigger to a specific gamepad:
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use press_here::{GamepadPolicy, Trigger};
+/// # struct Jump;
+/// fn assign_gamepad(mut commands: Commands, gamepads: Query<Entity, Added<Gamepad>>) {
+///     for gamepad in &gamepads {
+///         commands.spawn(
+///             Trigger::<Jump>::new(GamepadButton::South).with_gamepad(GamepadPolicy::Gamepad(gamepad)),
+///         );
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadPolicy {
+    /// Reads from all connected gamepads.
+    #[default]
+    Any,
+    /// Reads only from the given gamepad entity.
+    Gamepad(Entity),
+    /// Reads only from the gamepad that most recently had a button pressed or an axis moved. See [LastUsedGamepad].
+    LastUsed,
+}
+
+impl GamepadPolicy {
+    /// Returns true if the gamepad entity is allowed by this policy.
+    pub fn allows(&self, gamepad: Entity, inputs: &Inputs) -> bool {
+        match self {
+            GamepadPolicy::Any => true,
+            GamepadPolicy::Gamepad(entity) => *entity == gamepad,
+            GamepadPolicy::LastUsed => inputs.last_used_gamepad == Some(gamepad),
+        }
+    }
+}
+
+/// A resource holding the gamepad entity that most recently had a button pressed or an axis moved.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct LastUsedGamepad(pub Option<Entity>);
+
+pub(crate) fn update_last_used_gamepad(
+    mut last_used: ResMut<LastUsedGamepad>,
+    mut events: MessageReader<GamepadEvent>,
+) {
+    // Button and axis changes arrive in a single stream, so the last one read is the most recent usage.
+    let used = events
+        .read()
+        .filter_map(|event| match event {
+            GamepadEvent::Button(event) if event.state == ButtonState::Pressed => {
+                Some(event.entity)
+            }
+            GamepadEvent::Axis(event) if event.value.abs() >= AXIS_USAGE_THRESHOLD => {
+                Some(event.entity)
+            }
+            _ => None,
+        })
+        .last();
+
+    if let Some(entity) = used {
+        last_used.0 = Some(entity);
+    }
+}
+
+/// Scopes a binding (and all of its children) to the gamepads allowed by the given [GamepadPolicy]. Works for both axis
+/// and trigger bindings.
+///
+/// Gamepad entities change between runs, so a binding scoped to [GamepadPolicy::Gamepad] has no data representation and
+/// is left out of saved bindings.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AxisBinding, GamepadPolicy, OnGamepad, Pair};
+/// # let gamepad = Entity::PLACEHOLDER;
+/// let binding = (
+///     Pair(KeyCode::KeyA, KeyCode::KeyD),
+///     OnGamepad(GamepadAxis::LeftStickX, GamepadPolicy::Gamepad(gamepad)),
+/// );
+/// assert_eq!(binding.to_data(), None);
+///
+/// let binding = OnGamepad(GamepadAxis::LeftStickX, GamepadPolicy::LastUsed);
+/// assert!(binding.to_data().is_some());
+/// ```
+#[derive(Clone, Copy)]
+pub struct OnGamepad<B>(pub B, pub GamepadPolicy);
+
+impl<A: AxisBinding + Clone> AxisBinding for OnGamepad<A> {
+    fn value(&mut self, inputs: &Inputs) -> Option<f32> {
+        inputs.with_gamepad_policy(self.1, |inputs| self.0.value(inputs))
+    }
+
+    fn clone_axis(&self) -> Box<dyn AxisBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("on gamepad", vec![self.0.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.0)]
+    }
+
+    fn to_data(&self) -> Option<AxisBindingData> {
+        // Gamepad entities change between runs, so they can't be stored in saved bindings.
+        if let GamepadPolicy::Gamepad(_) = self.1 {
+            return None;
+        }
+        Some(AxisBindingData::OnGamepad(
+            Box::new(self.0.to_data()?),
+            self.1,
+        ))
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for OnGamepad<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        inputs.with_gamepad_policy(self.1, |inputs| self.0.evaluate(inputs))
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("on gamepad", vec![self.0.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.0)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        // Gamepad entities change between runs, so they can't be stored in saved bindings.
+        if let GamepadPolicy::Gamepad(_) = self.1 {
+            return None;
+        }
+        Some(TriggerBindingData::OnGamepad(
+            Box::new(self.0.to_data()?),
+            self.1,
+        ))
+    }
+}
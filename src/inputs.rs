@@ -1,15 +1,21 @@
+use crate::{
+    clock::{InputClock, InputClocks},
+    gamepad::{GamepadPolicy, LastUsedGamepad},
+    latch::LatchedInputs,
+};
 use bevy::{
     ecs::{
+        entity::Entity,
         message::MessageReader,
         system::{Query, Res, SystemParam},
     },
     input::{
         ButtonInput,
-        gamepad::Gamepad,
+        gamepad::{Gamepad, GamepadAxis, GamepadButton},
         keyboard::KeyCode,
         mouse::{MouseButton, MouseMotion, MouseWheel},
     },
-    time::{Real, Time},
+    time::Time,
 };
 
 /// A collection of input references for use in input bindings.
@@ -18,17 +24,119 @@ pub struct Inputs<'a> {
     pub mouse_buttons: &'a ButtonInput<MouseButton>,
     pub mouse_motion: &'a [&'a MouseMotion],
     pub mouse_wheel: &'a [&'a MouseWheel],
-    pub gamepads: &'a [&'a Gamepad],
-    pub time: &'a Time<Real>,
+    pub gamepads: &'a [(Entity, &'a Gamepad)],
+    pub last_used_gamepad: Option<Entity>,
+    /// The time of the clock selected for the action being updated. See [InputClock].
+    pub time: &'a Time,
+    pub clocks: &'a InputClocks,
+}
+
+impl Inputs<'_> {
+    /// Calls `f` with a copy of these inputs that reads time from the given clock.
+    pub fn with_clock<R>(&self, clock: InputClock, f: impl FnOnce(&Inputs) -> R) -> R {
+        f(&Inputs {
+            time: self.clocks.get(clock),
+            ..*self
+        })
+    }
+
+    /// Calls `f` with a copy of these inputs that only contains the gamepads allowed by the given policy.
+    pub fn with_gamepad_policy<R>(&self, policy: GamepadPolicy, f: impl FnOnce(&Inputs) -> R) -> R {
+        if policy == GamepadPolicy::Any {
+            return f(self);
+        }
+
+        let gamepads = self
+            .gamepads
+            .iter()
+            .copied()
+            .filter(|(entity, _)| policy.allows(*entity, self))
+            .collect::<Vec<_>>();
+
+        f(&Inputs {
+            gamepads: gamepads.as_slice(),
+            ..*self
+        })
+    }
+}
+
+/// A single physical input that can be bound, such as a key or a gamepad axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputSource {
+    Key(KeyCode),
+    MouseButton(MouseButton),
+    GamepadButton(GamepadButton),
+    GamepadAxis(GamepadAxis),
+}
+
+impl From<KeyCode> for InputSource {
+    fn from(key: KeyCode) -> Self {
+        InputSource::Key(key)
+    }
+}
+
+impl From<MouseButton> for InputSource {
+    fn from(button: MouseButton) -> Self {
+        InputSource::MouseButton(button)
+    }
+}
+
+impl From<GamepadButton> for InputSource {
+    fn from(button: GamepadButton) -> Self {
+        InputSource::GamepadButton(button)
+    }
+}
+
+impl From<GamepadAxis> for InputSource {
+    fn from(axis: GamepadAxis) -> Self {
+        InputSource::GamepadAxis(axis)
+    }
 }
 
 /// System parameter for accessing input resources.
 #[derive(SystemParam)]
 pub struct InputsSystemParam<'w, 's> {
     pub keycodes: Res<'w, ButtonInput<KeyCode>>,
-    pub gamepads: Query<'w, 's, &'static Gamepad>,
+    pub gamepads: Query<'w, 's, (Entity, &'static Gamepad)>,
+    pub last_used_gamepad: Res<'w, LastUsedGamepad>,
     pub mouse_buttons: Res<'w, ButtonInput<MouseButton>>,
     pub mouse_motion: MessageReader<'w, 's, MouseMotion>,
     pub mouse_wheel: MessageReader<'w, 's, MouseWheel>,
-    pub time: Res<'w, Time<Real>>,
+    pub clocks: Res<'w, InputClocks>,
+    latched: Option<Res<'w, LatchedInputs>>,
+}
+
+impl InputsSystemParam<'_, '_> {
+    /// Reads the buffered input messages and calls `f` with an [`Inputs`] built from them. If input latching is
+    /// enabled, the latched buttons are used instead of the buttons of this frame.
+    pub fn with_inputs<R>(&mut self, f: impl FnOnce(&Inputs) -> R) -> R {
+        let latched_gamepads = self
+            .latched
+            .as_ref()
+            .map(|latched| latched.gamepads(&self.gamepads));
+        let gamepads = match &latched_gamepads {
+            Some(latched) => latched.iter().map(|(entity, pad)| (*entity, pad)).collect(),
+            None => self.gamepads.iter().collect::<Vec<_>>(),
+        };
+        let mouse_motion = self.mouse_motion.read().collect::<Vec<_>>();
+        let mouse_wheel = self.mouse_wheel.read().collect::<Vec<_>>();
+
+        let (keycodes, mouse_buttons) = match &self.latched {
+            Some(latched) => (&latched.keycodes, &latched.mouse_buttons),
+            None => (&*self.keycodes, &*self.mouse_buttons),
+        };
+
+        let inputs = Inputs {
+            keycodes,
+            mouse_buttons,
+            mouse_motion: mouse_motion.as_slice(),
+            mouse_wheel: mouse_wheel.as_slice(),
+            gamepads: gamepads.as_slice(),
+            last_used_gamepad: self.last_used_gamepad.0,
+            time: self.clocks.get(self.clocks.default_clock()),
+            clocks: &self.clocks,
+        };
+
+        f(&inputs)
+    }
 }
@@ -0,0 +1,93 @@
+use bevy::{
+    ecs::{
+        entity::{Entity, EntityHashMap},
+        resource::Resource,
+        system::{Query, Res, ResMut},
+    },
+    input::{
+        ButtonInput,
+        gamepad::{Gamepad, GamepadButton},
+        keyboard::KeyCode,
+        mouse::MouseButton,
+    },
+};
+use std::hash::Hash;
+
+/// Button states collected every frame and consumed when actions are updated, so that actions updated in a schedule
+/// that doesn't run every frame (e.g. `FixedPreUpdate`) neither miss nor repeat presses. Only present when enabled with
+/// [PressHerePlugin::with_input_latching](crate::PressHerePlugin::with_input_latching).
+#[derive(Resource, Default)]
+pub(crate) struct LatchedInputs {
+    pub keycodes: ButtonInput<KeyCode>,
+    pub mouse_buttons: ButtonInput<MouseButton>,
+    pub gamepads: EntityHashMap<ButtonInput<GamepadButton>>,
+}
+
+impl LatchedInputs {
+    /// Returns copies of the gamepads with their buttons replaced by the latched buttons.
+    pub fn gamepads(&self, gamepads: &Query<(Entity, &Gamepad)>) -> Vec<(Entity, Gamepad)> {
+        gamepads
+            .iter()
+            .map(|(entity, source)| {
+                let mut gamepad = Gamepad::default();
+                for (input, value) in source.analog().all_axes_and_values() {
+                    gamepad.analog_mut().set(*input, value);
+                }
+                *gamepad.digital_mut() = self
+                    .gamepads
+                    .get(&entity)
+                    .cloned()
+                    .unwrap_or_else(|| source.digital().clone());
+                (entity, gamepad)
+            })
+            .collect()
+    }
+}
+
+/// Adds the presses and releases of this frame to the latched buttons. Edges stay set until they're consumed, and the
+/// held buttons are synced afterwards, e.g. after a release and press within one frame.
+fn latch<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    latched: &mut ButtonInput<T>,
+    source: &ButtonInput<T>,
+) {
+    for button in source.get_just_pressed() {
+        latched.press(*button);
+    }
+    for button in source.get_just_released() {
+        latched.release(*button);
+    }
+    for button in source.get_pressed() {
+        if !latched.pressed(*button) {
+            latched.press(*button);
+        }
+    }
+}
+
+pub(crate) fn latch_inputs(
+    mut latched: ResMut<LatchedInputs>,
+    keycodes: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+) {
+    let latched = &mut *latched;
+    latch(&mut latched.keycodes, &keycodes);
+    latch(&mut latched.mouse_buttons, &mouse_buttons);
+
+    latched
+        .gamepads
+        .retain(|entity, _| gamepads.contains(*entity));
+    for (entity, gamepad) in &gamepads {
+        latch(
+            latched.gamepads.entry(entity).or_default(),
+            gamepad.digital(),
+        );
+    }
+}
+
+pub(crate) fn consume_latched_inputs(mut latched: ResMut<LatchedInputs>) {
+    latched.keycodes.clear();
+    latched.mouse_buttons.clear();
+    for buttons in latched.gamepads.values_mut() {
+        buttons.clear();
+    }
+}
@@ -75,22 +75,45 @@ let binding = (
     .smooth(0.2);
 ```
 
+## Two-dimensional axes
+
+Movement and camera controls can use `Axis2`, which evaluates both components together and returns a `Vec2`:
+
+```no_run
+# use bevy::prelude::*;
+# use press_here::{AppExt, DualAxisBindingBuilder, GamepadStick, VirtualDpad};
+# struct Move;
+App::new().add_axis2::<Move>((
+    VirtualDpad::wasd().dual_normalize(),
+    GamepadStick::Left.radial_deadzone(0.1),
+));
+```
+
+## Input contexts
+
+Actions can be grouped into contexts, e.g. gameplay and menu. Actions in an inactive context report zero or unpressed, so opening a menu doesn't move the character:
+
+```no_run
+# use bevy::prelude::*;
+# use press_here::{AppExt, InputContext, InputContexts, Pair};
+# struct Walk;
+# struct Gameplay;
+# struct Menu;
+App::new()
+    .add_axis::<Walk>(Pair(KeyCode::KeyA, KeyCode::KeyD))
+    .set_action_context::<Walk>(InputContext::of::<Gameplay>());
+
+fn open_menu(mut contexts: ResMut<InputContexts>) {
+    contexts.push(InputContext::of::<Menu>());
+}
+```
+
 ## Next steps
 
 - Better documentation.
   - Currently most structs have some comments and the crate is rather straight forward, but better examples in comments could be provided.
-- Binding information.
-  - Add basic info to each binding so that we can know what bindings are set. Right now we would need to iterate over each binding and downcast its type. Not optimal.
-- Observer support.
-  - Currently you have to set up event triggering yourself. This can be streamlined.
-- Tests
-  - We could write simple unit tests for each binding. `Inputs`-mocking should be made easier. This would also allow us to make create better doc-comments/tests.
 - Referring to existing axes and triggers.
   - It would be nice to be able to refer to other already defined triggers and axes. This would allow us to do something like `.with_trigger::<SomeTrigger>()`.
-- Schedule configuration.
-  - Right now all checks are happending in the PreUpdate schedule. It might be desirable to configure this.
-- Time clock configuration.
-  - Currently bindings that rely on time use the `Time<Real>` clock. If a different clock is desired, we have no way to configure that.
 - ???
 
 ## Bevy compatibility
@@ -101,15 +124,43 @@ let binding = (
 */
 mod app;
 mod axis;
+mod axis2;
+mod capture;
+mod clock;
+mod conditions;
+mod conflicts;
+mod consume;
+mod context;
+mod describe;
+mod events;
+mod gamepad;
 mod inputs;
+mod latch;
+mod registry;
+mod settings;
+pub mod testing;
 mod trigger;
 mod visualizer;
 
-pub use app::AppExt;
+pub use app::{AppExt, PressHerePlugin, PressHereSystems};
 pub use axis::{
-    Axis, AxisBinding, bindings::*, builder::*, combinators::*, filters::*, modifiers::*,
+    Axis, AxisBinding, bindings::*, builder::*, combinators::*, data::*, filters::*, modifiers::*,
+};
+pub use axis2::{
+    Axis2, DualAxisBinding, bindings::*, builder::*, combinators::*, data::*, filters::*,
+};
+pub use capture::*;
+pub use clock::{InputClock, InputClocks};
+pub use conditions::*;
+pub use conflicts::BindingConflict;
+pub use context::{InputContext, InputContexts};
+pub use describe::*;
+pub use events::{Axis2Changed, AxisChanged, TriggerPressed, TriggerReleased};
+pub use gamepad::{GamepadPolicy, LastUsedGamepad, OnGamepad};
+pub use inputs::{InputSource, Inputs};
+pub use settings::*;
+pub use trigger::{
+    Trigger, TriggerBinding, TriggerState, builder::*, combinators::*, data::*, modifiers::*,
 };
-pub use inputs::Inputs;
-pub use trigger::{Trigger, TriggerBinding, builder::*, combinators::*, modifiers::*};
 #[cfg(feature = "visualizer")]
 pub use visualizer::*;
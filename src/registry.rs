@@ -0,0 +1,134 @@
+use crate::{
+    axis::{Axis, AxisBinding},
+    axis2::{Axis2, DualAxisBinding},
+    context::InputContext,
+    describe::BindingDescription,
+    trigger::{Trigger, TriggerBinding},
+};
+use bevy::{
+    ecs::{resource::Resource, world::World},
+    log::warn,
+    utils::prelude::ShortName,
+};
+use std::any::{TypeId, type_name};
+
+/// The state of a registered action that matters for input consumption and conflict detection.
+#[derive(Clone, Copy)]
+pub(crate) struct ActionState {
+    /// True if the action is pressed or has a non-zero value.
+    pub active: bool,
+    pub priority: i32,
+    pub context: Option<InputContext>,
+}
+
+/// An action resource that can be registered, implemented by [Trigger], [Axis], and [Axis2].
+pub(crate) trait RegistryAction: Resource {
+    type Binding: ?Sized + 'static;
+
+    fn binding(&self) -> &Self::Binding;
+    fn set_binding(&mut self, binding: Box<Self::Binding>);
+    fn state(&self) -> ActionState;
+    fn describe(&self) -> BindingDescription;
+    fn suppress(&mut self);
+}
+
+/// An axis or trigger resource registered through [AppExt](crate::AppExt), allowing its binding to be read and
+/// replaced without knowing the action type.
+pub(crate) struct RegisteredAction<B: ?Sized + 'static> {
+    pub type_id: TypeId,
+    pub type_name: &'static str,
+    pub name: String,
+    pub binding: fn(&World) -> Option<&B>,
+    pub set_binding: fn(&mut World, Box<B>),
+    pub state: fn(&World) -> Option<ActionState>,
+    pub describe: fn(&World) -> Option<BindingDescription>,
+    pub suppress: fn(&mut World),
+}
+
+/// The parts of a [RegisteredAction] that don't depend on the binding type.
+pub(crate) struct ActionHandle<'a> {
+    pub name: &'a str,
+    pub state: fn(&World) -> Option<ActionState>,
+    pub describe: fn(&World) -> Option<BindingDescription>,
+    pub suppress: fn(&mut World),
+}
+
+impl<B: ?Sized + 'static> RegisteredAction<B> {
+    fn new<M: 'static, R: RegistryAction<Binding = B>>() -> Self {
+        Self {
+            type_id: TypeId::of::<M>(),
+            type_name: type_name::<M>(),
+            name: ShortName::of::<M>().to_string(),
+            binding: |world| world.get_resource::<R>().map(R::binding),
+            set_binding: |world, binding| {
+                if let Some(mut action) = world.get_resource_mut::<R>() {
+                    action.set_binding(binding);
+                }
+            },
+            state: |world| world.get_resource::<R>().map(R::state),
+            describe: |world| world.get_resource::<R>().map(R::describe),
+            suppress: |world| {
+                if let Some(mut action) = world.get_resource_mut::<R>() {
+                    action.suppress();
+                }
+            },
+        }
+    }
+
+    fn handle(&self) -> ActionHandle<'_> {
+        ActionHandle {
+            name: &self.name,
+            state: self.state,
+            describe: self.describe,
+            suppress: self.suppress,
+        }
+    }
+}
+
+/// Keeps track of all axis and trigger resources added to the app.
+#[derive(Resource, Default)]
+pub(crate) struct ActionRegistry {
+    pub axes: Vec<RegisteredAction<dyn AxisBinding>>,
+    pub dual_axes: Vec<RegisteredAction<dyn DualAxisBinding>>,
+    pub triggers: Vec<RegisteredAction<dyn TriggerBinding>>,
+}
+
+impl ActionRegistry {
+    pub fn register_axis<A: Send + Sync + 'static>(&mut self) {
+        register::<A, Axis<A>>(&mut self.axes);
+    }
+
+    pub fn register_axis2<A: Send + Sync + 'static>(&mut self) {
+        register::<A, Axis2<A>>(&mut self.dual_axes);
+    }
+
+    pub fn register_trigger<T: Send + Sync + 'static>(&mut self) {
+        register::<T, Trigger<T>>(&mut self.triggers);
+    }
+
+    /// Returns all registered triggers, axes, and two-dimensional axes.
+    pub fn actions(&self) -> impl Iterator<Item = ActionHandle<'_>> {
+        let triggers = self.triggers.iter().map(RegisteredAction::handle);
+        let axes = self.axes.iter().map(RegisteredAction::handle);
+        let dual_axes = self.dual_axes.iter().map(RegisteredAction::handle);
+        triggers.chain(axes).chain(dual_axes)
+    }
+}
+
+fn register<M: 'static, R: RegistryAction>(actions: &mut Vec<RegisteredAction<R::Binding>>) {
+    let type_id = TypeId::of::<M>();
+    if actions.iter().any(|action| action.type_id == type_id) {
+        return;
+    }
+
+    let action = RegisteredAction::new::<M, R>();
+    // Saved bindings are keyed by the short name, so actions with the same name would share their entry.
+    if let Some(other) = actions.iter().find(|other| other.name == action.name) {
+        warn!(
+            "{} and {} are both named {}, so they share their entry in saved bindings",
+            other.type_name, action.type_name, action.name
+        );
+    }
+
+    actions.push(action);
+}
@@ -0,0 +1,248 @@
+use crate::{
+    axis::data::AxisBindingData,
+    axis2::data::DualAxisBindingData,
+    registry::{ActionRegistry, RegisteredAction},
+    trigger::data::TriggerBindingData,
+};
+use bevy::{ecs::world::World, log::warn};
+use std::collections::BTreeMap;
+#[cfg(feature = "serialize")]
+use {
+    bevy::ecs::{resource::Resource, system::Command},
+    std::{
+        fmt::{self, Display},
+        fs, io,
+        path::{Path, PathBuf},
+    },
+};
+
+/// The bindings of all axes, two-dimensional axes, and triggers added with [AppExt](crate::AppExt), keyed by the short
+/// type name of the action. Two actions with the same name in different modules would share an entry, so this is
+/// reported with a warning when they're added.
+///
+/// Bindings that have no data representation (see [AxisBindingData]) are left out with a warning. With the `serialize`
+/// feature enabled, the config can be read from and written to RON files.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "serialize")] {
+/// # use bevy::prelude::*;
+/// # use press_here::{AxisBindingData, BindingsConfig, TriggerBindingData};
+/// let mut config = BindingsConfig::default();
+/// config.triggers.insert("Jump".into(), TriggerBindingData::Key(KeyCode::Space));
+/// config.axes.insert(
+///     "Walk".into(),
+///     AxisBindingData::Pair(
+///         Box::new(AxisBindingData::Key(KeyCode::KeyA)),
+///         Box::new(AxisBindingData::Key(KeyCode::KeyD)),
+///     ),
+/// );
+///
+/// let text = config.to_ron().unwrap();
+/// assert_eq!(BindingsConfig::from_ron(&text).unwrap(), config);
+/// # }
+/// ```
+///
+/// Reading and replacing the bindings of an app:
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, BindingsConfig, Trigger, TriggerBinding, TriggerBindingData};
+/// let mut app = App::new();
+/// app.add_trigger::<Jump>(KeyCode::Space);
+///
+/// let mut config = BindingsConfig::from_world(app.world());
+/// assert_eq!(config.triggers["Jump"], TriggerBindingData::Key(KeyCode::Space));
+///
+/// config.triggers.insert("Jump".into(), TriggerBindingData::Key(KeyCode::Enter));
+/// config.apply(app.world_mut());
+///
+/// let jump = app.world().resource::<Trigger<Jump>>();
+/// assert_eq!(jump.binding().to_data(), Some(TriggerBindingData::Key(KeyCode::Enter)));
+///
+/// struct Jump;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BindingsConfig {
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub axes: BTreeMap<String, AxisBindingData>,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub dual_axes: BTreeMap<String, DualAxisBindingData>,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub triggers: BTreeMap<String, TriggerBindingData>,
+}
+
+impl BindingsConfig {
+    /// Collects the current bindings of all registered axis, two-dimensional axis, and trigger resources. Actions whose
+    /// binding has no data representation are skipped with a warning.
+    pub fn from_world(world: &World) -> Self {
+        let Some(registry) = world.get_resource::<ActionRegistry>() else {
+            return Self::default();
+        };
+
+        Self {
+            axes: collect(world, &registry.axes, |binding| binding.to_data()),
+            dual_axes: collect(world, &registry.dual_axes, |binding| binding.to_data()),
+            triggers: collect(world, &registry.triggers, |binding| binding.to_data()),
+        }
+    }
+
+    /// Replaces the bindings of all registered axis, two-dimensional axis, and trigger resources that have an entry in
+    /// this config. Entries without a matching action are ignored.
+    pub fn apply(&self, world: &mut World) {
+        let Some(registry) = world.remove_resource::<ActionRegistry>() else {
+            return;
+        };
+
+        replace(
+            world,
+            &registry.axes,
+            &self.axes,
+            AxisBindingData::into_binding,
+        );
+        replace(
+            world,
+            &registry.dual_axes,
+            &self.dual_axes,
+            DualAxisBindingData::into_binding,
+        );
+        replace(
+            world,
+            &registry.triggers,
+            &self.triggers,
+            TriggerBindingData::into_binding,
+        );
+
+        world.insert_resource(registry);
+    }
+}
+
+/// Collects the data descriptions of the bindings of `actions`, keyed by their names.
+fn collect<B: ?Sized, D>(
+    world: &World,
+    actions: &[RegisteredAction<B>],
+    to_data: impl Fn(&B) -> Option<D>,
+) -> BTreeMap<String, D> {
+    let mut entries = BTreeMap::new();
+    for action in actions {
+        let Some(binding) = (action.binding)(world) else {
+            continue;
+        };
+        match to_data(binding) {
+            Some(data) => {
+                entries.insert(action.name.clone(), data);
+            }
+            None => warn!(
+                "The binding of {} has no data representation and is left out of the config",
+                action.name
+            ),
+        }
+    }
+    entries
+}
+
+/// Replaces the bindings of `actions` that have an entry in `entries`.
+fn replace<B: ?Sized, D: Clone>(
+    world: &mut World,
+    actions: &[RegisteredAction<B>],
+    entries: &BTreeMap<String, D>,
+    into_binding: fn(D) -> Box<B>,
+) {
+    for action in actions {
+        if let Some(data) = entries.get(&action.name) {
+            (action.set_binding)(world, into_binding(data.clone()));
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl BindingsConfig {
+    /// Parses a config from a RON string.
+    pub fn from_ron(text: &str) -> Result<Self, BindingsFileError> {
+        ron::from_str(text).map_err(BindingsFileError::Deserialize)
+    }
+
+    /// Serializes this config to a pretty-printed RON string.
+    pub fn to_ron(&self) -> Result<String, BindingsFileError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(BindingsFileError::Serialize)
+    }
+
+    /// Reads a config from a RON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BindingsFileError> {
+        let text = fs::read_to_string(path).map_err(BindingsFileError::Io)?;
+        Self::from_ron(&text)
+    }
+
+    /// Writes this config to a RON file, creating parent directories if needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BindingsFileError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(BindingsFileError::Io)?;
+        }
+
+        fs::write(path, self.to_ron()?).map_err(BindingsFileError::Io)
+    }
+}
+
+#[cfg(feature = "serialize")]
+/// A resource holding the path of the settings file set with
+/// [AppExt::load_bindings](crate::AppExt::load_bindings).
+#[derive(Resource, Clone, Debug)]
+pub struct BindingsFile(pub PathBuf);
+
+#[cfg(feature = "serialize")]
+/// A command that writes the current bindings to the path in the [BindingsFile] resource.
+///
+/// # Examples
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use press_here::SaveBindings;
+/// fn on_rebind_finished(mut commands: Commands) {
+///     commands.queue(SaveBindings);
+/// }
+/// ```
+pub struct SaveBindings;
+
+#[cfg(feature = "serialize")]
+impl Command<Result<(), BindingsFileError>> for SaveBindings {
+    fn apply(self, world: &mut World) -> Result<(), BindingsFileError> {
+        let path = world
+            .get_resource::<BindingsFile>()
+            .map(|file| file.0.clone())
+            .ok_or(BindingsFileError::MissingPath)?;
+
+        BindingsConfig::from_world(world).save(path)
+    }
+}
+
+#[cfg(feature = "serialize")]
+/// Errors that can occur when reading or writing a bindings settings file.
+#[derive(Debug)]
+pub enum BindingsFileError {
+    /// The file could not be read or written.
+    Io(io::Error),
+    /// The file contents are not a valid [BindingsConfig].
+    Deserialize(ron::error::SpannedError),
+    /// The config could not be serialized.
+    Serialize(ron::Error),
+    /// No [BindingsFile] resource was found.
+    MissingPath,
+}
+
+#[cfg(feature = "serialize")]
+impl Display for BindingsFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BindingsFileError::Io(error) => write!(f, "failed to access bindings file: {error}"),
+            BindingsFileError::Deserialize(error) => write!(f, "invalid bindings file: {error}"),
+            BindingsFileError::Serialize(error) => {
+                write!(f, "failed to serialize bindings: {error}")
+            }
+            BindingsFileError::MissingPath => write!(f, "no bindings file path has been set"),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl std::error::Error for BindingsFileError {}
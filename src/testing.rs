@@ -0,0 +1,365 @@
+//! Utilities for testing bindings and actions without a window or real input devices.
+
+use crate::{
+    clock::{InputClock, InputClocks},
+    inputs::{InputSource, Inputs},
+};
+use bevy::{
+    app::{App, Last},
+    ecs::{
+        entity::Entity,
+        resource::Resource,
+        system::{Query, ResMut},
+        world::{Mut, World},
+    },
+    input::{
+        ButtonInput,
+        gamepad::{Gamepad, GamepadAxis, GamepadEvent},
+        keyboard::KeyCode,
+        mouse::{MouseButton, MouseMotion, MouseScrollUnit, MouseWheel},
+    },
+    math::Vec2,
+    time::{Fixed, Real, Time, Virtual},
+};
+use std::time::Duration;
+
+/// A set of fake inputs that bindings can be evaluated against directly.
+///
+/// Pressed buttons stay pressed until released, just like real ones. Call [next_frame](MockInputs::next_frame) between
+/// evaluations to clear `just_pressed`/`just_released`, mouse movement, and the time delta.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AxisBinding, AxisBindingBuilder, Pair, TriggerBinding, testing::MockInputs};
+/// let mut binding = (Pair(KeyCode::KeyA, KeyCode::KeyD), GamepadAxis::LeftStickX.deadzone(0.1));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::KeyD);
+/// assert_eq!(mock.with_inputs(|inputs| binding.value(inputs)), Some(1.0));
+///
+/// // The stick is within the deadzone and no key is pressed, so the binding has no value.
+/// mock.release(KeyCode::KeyD).set_axis(GamepadAxis::LeftStickX, 0.05);
+/// assert_eq!(mock.with_inputs(|inputs| binding.value(inputs)), None);
+///
+/// let mut jump = KeyCode::Space;
+/// mock.next_frame().press(KeyCode::Space);
+/// assert!(mock.with_inputs(|inputs| jump.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame();
+/// assert!(!mock.with_inputs(|inputs| jump.evaluate(inputs).just_pressed));
+/// ```
+pub struct MockInputs {
+    keycodes: ButtonInput<KeyCode>,
+    mouse_buttons: ButtonInput<MouseButton>,
+    mouse_motion: Vec<MouseMotion>,
+    mouse_wheel: Vec<MouseWheel>,
+    gamepads: Vec<(Entity, Gamepad)>,
+    last_used_gamepad: Option<Entity>,
+    clocks: InputClocks,
+}
+
+impl Default for MockInputs {
+    fn default() -> Self {
+        Self {
+            keycodes: ButtonInput::default(),
+            mouse_buttons: ButtonInput::default(),
+            mouse_motion: Vec::new(),
+            mouse_wheel: Vec::new(),
+            gamepads: Vec::new(),
+            last_used_gamepad: None,
+            clocks: InputClocks::new(InputClock::Real),
+        }
+    }
+}
+
+impl MockInputs {
+    /// The gamepad that [press](MockInputs::press) and [set_axis](MockInputs::set_axis) apply gamepad inputs to.
+    pub const GAMEPAD: Entity = Entity::PLACEHOLDER;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Presses a key or button. Gamepad axes are set to `1.0`.
+    pub fn press(&mut self, input: impl Into<InputSource>) -> &mut Self {
+        match input.into() {
+            InputSource::Key(key) => self.keycodes.press(key),
+            InputSource::MouseButton(button) => self.mouse_buttons.press(button),
+            input => set_gamepad_input(self.gamepad_mut(Self::GAMEPAD), input, true),
+        }
+        self
+    }
+
+    /// Releases a key or button. Gamepad axes are set to `0.0`.
+    pub fn release(&mut self, input: impl Into<InputSource>) -> &mut Self {
+        match input.into() {
+            InputSource::Key(key) => self.keycodes.release(key),
+            InputSource::MouseButton(button) => self.mouse_buttons.release(button),
+            input => set_gamepad_input(self.gamepad_mut(Self::GAMEPAD), input, false),
+        }
+        self
+    }
+
+    /// Sets the value of a gamepad axis.
+    pub fn set_axis(&mut self, axis: GamepadAxis, value: f32) -> &mut Self {
+        self.gamepad_mut(Self::GAMEPAD)
+            .analog_mut()
+            .set(axis, value);
+        self
+    }
+
+    /// Adds mouse movement for this frame.
+    pub fn move_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.mouse_motion.push(MouseMotion { delta });
+        self
+    }
+
+    /// Adds mouse wheel scrolling for this frame.
+    pub fn scroll(&mut self, unit: MouseScrollUnit, delta: Vec2) -> &mut Self {
+        self.mouse_wheel.push(MouseWheel {
+            unit,
+            x: delta.x,
+            y: delta.y,
+            window: Entity::PLACEHOLDER,
+        });
+        self
+    }
+
+    /// Advances all clocks by the given duration. The time delta is kept until the next frame.
+    pub fn advance(&mut self, delta: Duration) -> &mut Self {
+        self.clocks.advance_by(delta);
+        self
+    }
+
+    /// Sets the clock that time-based bindings read from. Defaults to [InputClock::Real].
+    pub fn set_clock(&mut self, clock: InputClock) -> &mut Self {
+        self.clocks.set_default_clock(clock);
+        self
+    }
+
+    /// Sets the gamepad used by [GamepadPolicy::LastUsed](crate::GamepadPolicy::LastUsed).
+    pub fn set_last_used_gamepad(&mut self, gamepad: Option<Entity>) -> &mut Self {
+        self.last_used_gamepad = gamepad;
+        self
+    }
+
+    /// Returns the gamepad with the given entity, adding it if it doesn't exist yet. Use this to test bindings with
+    /// multiple gamepads.
+    pub fn gamepad_mut(&mut self, gamepad: Entity) -> &mut Gamepad {
+        let index = match self
+            .gamepads
+            .iter()
+            .position(|(entity, _)| *entity == gamepad)
+        {
+            Some(index) => index,
+            None => {
+                self.gamepads.push((gamepad, Gamepad::default()));
+                self.gamepads.len() - 1
+            }
+        };
+        &mut self.gamepads[index].1
+    }
+
+    /// Starts a new frame: clears `just_pressed`/`just_released`, mouse movement, and the time delta.
+    pub fn next_frame(&mut self) -> &mut Self {
+        self.keycodes.clear();
+        self.mouse_buttons.clear();
+        for (_, gamepad) in &mut self.gamepads {
+            gamepad.digital_mut().clear();
+        }
+        self.mouse_motion.clear();
+        self.mouse_wheel.clear();
+        self.clocks.advance_by(Duration::ZERO);
+        self
+    }
+
+    /// Calls `f` with an [`Inputs`] built from the mocked inputs.
+    pub fn with_inputs<R>(&self, f: impl FnOnce(&Inputs) -> R) -> R {
+        let gamepads = self
+            .gamepads
+            .iter()
+            .map(|(entity, gamepad)| (*entity, gamepad))
+            .collect::<Vec<_>>();
+        let mouse_motion = self.mouse_motion.iter().collect::<Vec<_>>();
+        let mouse_wheel = self.mouse_wheel.iter().collect::<Vec<_>>();
+
+        f(&Inputs {
+            keycodes: &self.keycodes,
+            mouse_buttons: &self.mouse_buttons,
+            mouse_motion: mouse_motion.as_slice(),
+            mouse_wheel: mouse_wheel.as_slice(),
+            gamepads: gamepads.as_slice(),
+            last_used_gamepad: self.last_used_gamepad,
+            time: self.clocks.get(self.clocks.default_clock()),
+            clocks: &self.clocks,
+        })
+    }
+}
+
+/// Applies a gamepad button or axis input. Buttons also get an analog value, like real gamepads report.
+fn set_gamepad_input(gamepad: &mut Gamepad, input: InputSource, pressed: bool) {
+    let value = if pressed { 1.0 } else { 0.0 };
+    match input {
+        InputSource::GamepadButton(button) => {
+            if pressed {
+                gamepad.digital_mut().press(button);
+            } else {
+                gamepad.digital_mut().release(button);
+            }
+            gamepad.analog_mut().set(button, value);
+        }
+        InputSource::GamepadAxis(axis) => {
+            gamepad.analog_mut().set(axis, value);
+        }
+        InputSource::Key(_) | InputSource::MouseButton(_) => {}
+    }
+}
+
+/// The gamepad entity spawned by [MockAppExt::init_mock_inputs].
+#[derive(Resource)]
+struct MockGamepad(Entity);
+
+/// Drives an [App] with fake inputs, so that actions added with [AppExt](crate::AppExt) can be tested headlessly.
+///
+/// Inputs set between two `app.update()` calls are seen by the actions in the next update. `just_pressed`,
+/// `just_released`, and the time delta are cleared at the end of every update.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AppExt, Axis, Pair, Trigger, testing::MockAppExt};
+/// # use std::time::Duration;
+/// let mut app = App::new();
+/// app.init_mock_inputs()
+///     .add_trigger::<Jump>(KeyCode::Space)
+///     .add_axis::<Walk>(Pair(KeyCode::KeyA, KeyCode::KeyD));
+///
+/// app.press(KeyCode::Space).press(KeyCode::KeyD).update();
+/// assert!(app.world().resource::<Trigger<Jump>>().just_pressed());
+/// assert_eq!(app.world().resource::<Axis<Walk>>().value(), 1.0);
+///
+/// app.advance(Duration::from_millis(16)).update();
+/// assert!(app.world().resource::<Trigger<Jump>>().pressed());
+/// assert!(!app.world().resource::<Trigger<Jump>>().just_pressed());
+///
+/// app.release(KeyCode::Space).update();
+/// assert!(app.world().resource::<Trigger<Jump>>().just_released());
+///
+/// # struct Jump;
+/// # struct Walk;
+/// ```
+pub trait MockAppExt {
+    fn init_mock_inputs(&mut self) -> &mut Self;
+    fn press(&mut self, input: impl Into<InputSource>) -> &mut Self;
+    fn release(&mut self, input: impl Into<InputSource>) -> &mut Self;
+    fn set_axis(&mut self, axis: GamepadAxis, value: f32) -> &mut Self;
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self;
+    fn scroll(&mut self, unit: MouseScrollUnit, delta: Vec2) -> &mut Self;
+    fn advance(&mut self, delta: Duration) -> &mut Self;
+}
+
+impl MockAppExt for App {
+    /// Inserts the input and time resources that Bevy's input and time plugins would provide, and spawns a gamepad
+    /// entity that gamepad inputs are applied to. Don't use this together with those plugins.
+    fn init_mock_inputs(&mut self) -> &mut Self {
+        let gamepad = self.world_mut().spawn(Gamepad::default()).id();
+
+        self.insert_resource(MockGamepad(gamepad))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<Time<Virtual>>()
+            .init_resource::<Time<Fixed>>()
+            .add_message::<MouseMotion>()
+            .add_message::<MouseWheel>()
+            .add_message::<GamepadEvent>()
+            .add_systems(Last, next_mock_frame)
+    }
+
+    /// Presses a key or button. Gamepad axes are set to `1.0`.
+    fn press(&mut self, input: impl Into<InputSource>) -> &mut Self {
+        set_world_input(self.world_mut(), input.into(), true);
+        self
+    }
+
+    /// Releases a key or button. Gamepad axes are set to `0.0`.
+    fn release(&mut self, input: impl Into<InputSource>) -> &mut Self {
+        set_world_input(self.world_mut(), input.into(), false);
+        self
+    }
+
+    /// Sets the value of a gamepad axis on the mock gamepad.
+    fn set_axis(&mut self, axis: GamepadAxis, value: f32) -> &mut Self {
+        mock_gamepad(self.world_mut()).analog_mut().set(axis, value);
+        self
+    }
+
+    /// Writes a [MouseMotion] message.
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.world_mut().write_message(MouseMotion { delta });
+        self
+    }
+
+    /// Writes a [MouseWheel] message.
+    fn scroll(&mut self, unit: MouseScrollUnit, delta: Vec2) -> &mut Self {
+        self.world_mut().write_message(MouseWheel {
+            unit,
+            x: delta.x,
+            y: delta.y,
+            window: Entity::PLACEHOLDER,
+        });
+        self
+    }
+
+    /// Advances `Time<Real>`, `Time<Virtual>`, and `Time<Fixed>` by the given duration for the next update.
+    fn advance(&mut self, delta: Duration) -> &mut Self {
+        let world = self.world_mut();
+        world.resource_mut::<Time<Real>>().advance_by(delta);
+        world.resource_mut::<Time<Virtual>>().advance_by(delta);
+        world.resource_mut::<Time<Fixed>>().advance_by(delta);
+        self
+    }
+}
+
+fn mock_gamepad(world: &mut World) -> Mut<'_, Gamepad> {
+    let entity = world
+        .get_resource::<MockGamepad>()
+        .expect("call `init_mock_inputs` before setting inputs")
+        .0;
+    world
+        .get_mut::<Gamepad>(entity)
+        .expect("the mock gamepad should not be despawned")
+}
+
+fn set_world_input(world: &mut World, input: InputSource, pressed: bool) {
+    match (input, pressed) {
+        (InputSource::Key(key), true) => world.resource_mut::<ButtonInput<KeyCode>>().press(key),
+        (InputSource::Key(key), false) => world.resource_mut::<ButtonInput<KeyCode>>().release(key),
+        (InputSource::MouseButton(button), true) => world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(button),
+        (InputSource::MouseButton(button), false) => world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(button),
+        (input, pressed) => set_gamepad_input(&mut mock_gamepad(world), input, pressed),
+    }
+}
+
+fn next_mock_frame(
+    mut keycodes: ResMut<ButtonInput<KeyCode>>,
+    mut mouse_buttons: ResMut<ButtonInput<MouseButton>>,
+    mut gamepads: Query<&mut Gamepad>,
+    mut real: ResMut<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut fixed: ResMut<Time<Fixed>>,
+) {
+    keycodes.clear();
+    mouse_buttons.clear();
+    for mut gamepad in &mut gamepads {
+        gamepad.digital_mut().clear();
+    }
+    real.advance_by(Duration::ZERO);
+    virtual_time.advance_by(Duration::ZERO);
+    fixed.advance_by(Duration::ZERO);
+}
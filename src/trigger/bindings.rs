@@ -1,110 +1,157 @@
-use crate::{inputs::Inputs, trigger::TriggerBinding};
+use crate::{
+    describe::{BindingDescription, BindingMut, InputMut},
+    inputs::Inputs,
+    trigger::{TriggerBinding, TriggerState, data::TriggerBindingData},
+};
 use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode, mouse::MouseButton};
 
 impl TriggerBinding for () {
-    fn pressed(&mut self, _: &Inputs) -> bool {
-        false
+    fn evaluate(&mut self, _: &Inputs) -> TriggerState {
+        TriggerState::default()
     }
 
-    fn just_pressed(&mut self, _: &Inputs) -> bool {
-        false
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(())
     }
 
-    fn just_released(&mut self, _: &Inputs) -> bool {
-        false
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named("None")
     }
 
-    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
-        Box::new(())
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::None)
     }
 }
 
 impl TriggerBinding for bool {
-    fn pressed(&mut self, _: &Inputs) -> bool {
-        *self
+    fn evaluate(&mut self, _: &Inputs) -> TriggerState {
+        TriggerState::transition(*self, *self)
     }
 
-    fn just_pressed(&mut self, _: &Inputs) -> bool {
-        false
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(*self)
     }
 
-    fn just_released(&mut self, _: &Inputs) -> bool {
-        false
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named(if *self { "Always" } else { "Never" })
     }
 
-    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
-        Box::new(*self)
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Constant(*self))
     }
 }
 
 impl TriggerBinding for KeyCode {
-    fn pressed(&mut self, inputs: &Inputs) -> bool {
-        inputs.keycodes.pressed(*self)
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        TriggerState {
+            pressed: inputs.keycodes.pressed(*self),
+            just_pressed: inputs.keycodes.just_pressed(*self),
+            just_released: inputs.keycodes.just_released(*self),
+            progress: None,
+        }
     }
 
-    fn just_pressed(&mut self, inputs: &Inputs) -> bool {
-        inputs.keycodes.just_pressed(*self)
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(*self)
     }
 
-    fn just_released(&mut self, inputs: &Inputs) -> bool {
-        inputs.keycodes.just_released(*self)
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::input(*self)
     }
 
-    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
-        Box::new(*self)
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        Some(InputMut::Key(self))
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Key(*self))
     }
 }
 
 impl TriggerBinding for MouseButton {
-    fn pressed(&mut self, inputs: &Inputs) -> bool {
-        inputs.mouse_buttons.pressed(*self)
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        TriggerState {
+            pressed: inputs.mouse_buttons.pressed(*self),
+            just_pressed: inputs.mouse_buttons.just_pressed(*self),
+            just_released: inputs.mouse_buttons.just_released(*self),
+            progress: None,
+        }
     }
 
-    fn just_pressed(&mut self, inputs: &Inputs) -> bool {
-        inputs.mouse_buttons.just_pressed(*self)
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(*self)
     }
 
-    fn just_released(&mut self, inputs: &Inputs) -> bool {
-        inputs.mouse_buttons.just_released(*self)
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::input(*self)
     }
 
-    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
-        Box::new(*self)
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        Some(InputMut::MouseButton(self))
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::MouseButton(*self))
     }
 }
 
 impl TriggerBinding for GamepadButton {
-    fn pressed(&mut self, inputs: &Inputs) -> bool {
-        inputs.gamepads.iter().any(|pad| pad.pressed(*self))
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        TriggerState::any(inputs.gamepads.iter().map(|(_, pad)| TriggerState {
+            pressed: pad.pressed(*self),
+            just_pressed: pad.just_pressed(*self),
+            just_released: pad.just_released(*self),
+            progress: None,
+        }))
     }
 
-    fn just_pressed(&mut self, inputs: &Inputs) -> bool {
-        inputs.gamepads.iter().any(|pad| pad.just_pressed(*self))
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(*self)
     }
 
-    fn just_released(&mut self, inputs: &Inputs) -> bool {
-        inputs.gamepads.iter().any(|pad| pad.just_released(*self))
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::input(*self)
     }
 
-    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
-        Box::new(*self)
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        Some(InputMut::GamepadButton(self))
     }
-}
 
-impl TriggerBinding for Box<dyn TriggerBinding> {
-    fn pressed(&mut self, inputs: &Inputs) -> bool {
-        self.as_mut().pressed(inputs)
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::GamepadButton(*self))
     }
+}
 
-    fn just_pressed(&mut self, inputs: &Inputs) -> bool {
-        self.as_mut().just_pressed(inputs)
+impl TriggerBinding for Box<dyn TriggerBinding> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        self.as_mut().evaluate(inputs)
     }
 
-    fn just_released(&mut self, inputs: &Inputs) -> bool {
-        self.as_mut().just_released(inputs)
+    fn reset(&mut self) {
+        self.as_mut().reset();
     }
 
     fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
         self.clone()
     }
+
+    fn describe(&self) -> BindingDescription {
+        self.as_ref().describe()
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        self.as_mut().children_mut()
+    }
+
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        self.as_mut().input_mut()
+    }
+
+    fn all_triggers(&self) -> Vec<Box<dyn TriggerBinding>> {
+        self.as_ref().all_triggers()
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        self.as_ref().to_data()
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{And, Not, TriggerBinding};
+use crate::{
+    And, Chord, Cooldown, Debounce, Hold, LongPress, ModifierKey, MultiTap, Not, Repeat, Tap,
+    Toggle, TriggerBinding,
+};
+use std::time::Duration;
 
 pub trait TriggerBindingBuilder: TriggerBinding + Sized {
     /// Returns a new trigger binding that is only active when both this and the given trigger binding are active.
@@ -10,4 +14,62 @@ pub trait TriggerBindingBuilder: TriggerBinding + Sized {
     fn not(self) -> Not<Self> {
         Not(self)
     }
+
+    /// Returns a new trigger binding that is only active when this binding is pressed while the given modifiers are
+    /// held. See [Chord].
+    fn with_modifiers(self, modifiers: impl IntoIterator<Item = ModifierKey>) -> Chord<Self> {
+        Chord::new(modifiers, self)
+    }
+
+    /// Returns a new trigger binding that is only pressed after this binding has been held for the given duration.
+    fn hold(self, duration: Duration) -> Hold<Self> {
+        Hold::new(self, duration)
+    }
+
+    /// Returns a new trigger binding that is pressed for a frame when this binding is released within the given
+    /// duration.
+    fn tap(self, max_duration: Duration) -> Tap<Self> {
+        Tap::new(self, max_duration)
+    }
+
+    /// Returns a new trigger binding that is pressed for a frame when this binding is released after being held for
+    /// at least the given duration.
+    fn long_press(self, duration: Duration) -> LongPress<Self> {
+        LongPress::new(self, duration)
+    }
+
+    /// Returns a new trigger binding that activates when this binding is pressed `count` times, with at most
+    /// `max_interval` between each press.
+    fn multi_tap(self, count: u32, max_interval: Duration) -> MultiTap<Self> {
+        MultiTap::new(self, count, max_interval)
+    }
+
+    /// Returns a new trigger binding that activates when this binding is pressed twice within `max_interval`.
+    fn double_tap(self, max_interval: Duration) -> MultiTap<Self> {
+        MultiTap::new(self, 2, max_interval)
+    }
+
+    /// Returns a new trigger binding that flips between pressed and released each time this binding is pressed. See
+    /// [Toggle].
+    fn toggle(self) -> Toggle<Self> {
+        Toggle::new(self)
+    }
+
+    /// Returns a new trigger binding that ignores presses of this binding for the given duration after it activated.
+    fn cooldown(self, duration: Duration) -> Cooldown<Self> {
+        Cooldown::new(self, duration)
+    }
+
+    /// Returns a new trigger binding that ignores changes of this binding for the given duration after it changed.
+    fn debounce(self, duration: Duration) -> Debounce<Self> {
+        Debounce::new(self, duration)
+    }
+
+    /// Returns a new trigger binding that is just pressed again every `interval` while this binding is held, after an
+    /// initial delay. See [Repeat].
+    fn repeat(self, initial_delay: Duration, interval: Duration) -> Repeat<Self> {
+        Repeat::new(self, initial_delay, interval)
+    }
 }
+
+impl<T: TriggerBinding> TriggerBindingBuilder for T {}
@@ -1,51 +1,256 @@
-use crate::{inputs::Inputs, trigger::TriggerBinding};
+use crate::{
+    describe::{BindingDescription, BindingKind, BindingMut},
+    inputs::Inputs,
+    trigger::{TriggerBinding, TriggerState, data::TriggerBindingData},
+};
+use bevy::input::keyboard::KeyCode;
 use pastey::paste;
+use std::{collections::VecDeque, time::Duration};
 
 /// A combinator that returns true only if both trigger bindings are pressed.
 #[derive(Clone, Copy)]
 pub struct And<T1: TriggerBinding, T2: TriggerBinding>(pub T1, pub T2);
 
 impl<T1: TriggerBinding + Clone, T2: TriggerBinding + Clone> TriggerBinding for And<T1, T2> {
-    fn pressed(&mut self, inputs: &Inputs) -> bool {
-        self.0.pressed(inputs) && self.1.pressed(inputs)
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let (a, b) = (self.0.evaluate(inputs), self.1.evaluate(inputs));
+        let mut state =
+            TriggerState::transition(a.previous() && b.previous(), a.pressed && b.pressed);
+        state.progress = match (a.progress, b.progress) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        state
     }
 
-    fn just_pressed(&mut self, inputs: &Inputs) -> bool {
-        let is_pressed = self.pressed(inputs);
-        let was_pressed = self.0.just_pressed(inputs) || self.1.just_pressed(inputs);
-        is_pressed && was_pressed
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::All)
+            .with_children(vec![self.0.describe(), self.1.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![
+            BindingMut::Trigger(&mut self.0),
+            BindingMut::Trigger(&mut self.1),
+        ]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::And(
+            Box::new(self.0.to_data()?),
+            Box::new(self.1.to_data()?),
+        ))
     }
+}
 
-    fn just_released(&mut self, inputs: &Inputs) -> bool {
-        let is_released = !self.pressed(inputs);
-        let was_released = self.0.just_released(inputs) || self.1.just_released(inputs);
-        is_released && was_released
+impl<T: TriggerBinding + Clone> TriggerBinding for Vec<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        TriggerState::any(self.iter_mut().map(|binding| binding.evaluate(inputs)))
     }
 
     fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Any)
+            .with_children(self.iter().map(|binding| binding.describe()).collect())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        self.iter_mut()
+            .map(|binding| BindingMut::Trigger(binding))
+            .collect()
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        self.iter()
+            .map(|binding| binding.to_data())
+            .collect::<Option<_>>()
+            .map(TriggerBindingData::Any)
+    }
+
+    fn all_triggers(&self) -> Vec<Box<dyn TriggerBinding>> {
+        self.iter().map(|b| b.clone_trigger()).collect()
+    }
 }
 
-impl<T: TriggerBinding + Clone> TriggerBinding for Vec<T> {
-    fn pressed(&mut self, inputs: &Inputs) -> bool {
-        self.iter_mut().any(|binding| binding.pressed(inputs))
+/// A combinator that activates when its steps are pressed in order, e.g. a fighting game motion like "down,
+/// down-forward, forward + punch". Each step must be pressed within `step_window` of the previous step, and the whole
+/// sequence within `total_window`. Presses are kept in a buffer for the length of `total_window`, so unrelated presses
+/// in between don't break the sequence.
+///
+/// `leniency` is the number of steps in the middle of the sequence that may be missed, e.g. a leniency of 1 also
+/// accepts "down, forward + punch" for the motion above. The first and last step are always required.
+///
+/// The sequence is `just_pressed` when the last step is pressed, and stays pressed while the last step is held.
+///
+/// # Examples
+/// ```
+/// # use bevy::{math::CompassOctant, prelude::*};
+/// # use press_here::{DualAxisBindingBuilder, Sequence, TriggerBinding, TriggerBindingBuilder, VirtualDpad, testing::MockInputs};
+/// let dpad = VirtualDpad::arrows();
+/// let mut fireball = Sequence::new()
+///     .then(dpad.direction(CompassOctant::South))
+///     .then(dpad.direction(CompassOctant::SouthEast))
+///     .then(dpad.direction(CompassOctant::East).and(KeyCode::KeyP));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::ArrowDown);
+/// mock.with_inputs(|inputs| fireball.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::ArrowRight);
+/// mock.with_inputs(|inputs| fireball.evaluate(inputs));
+/// mock.next_frame().release(KeyCode::ArrowDown).press(KeyCode::KeyP);
+/// assert!(mock.with_inputs(|inputs| fireball.evaluate(inputs).just_pressed));
+/// ```
+#[derive(Clone)]
+pub struct Sequence {
+    pub steps: Vec<Box<dyn TriggerBinding>>,
+    pub step_window: Duration,
+    pub total_window: Duration,
+    pub leniency: usize,
+    history: VecDeque<(Duration, Vec<usize>)>,
+    active: bool,
+}
+
+impl Default for Sequence {
+    /// An empty sequence with a step window of 0.25 seconds and a total window of 1 second.
+    fn default() -> Self {
+        Self {
+            steps: Vec::new(),
+            step_window: Duration::from_millis(250),
+            total_window: Duration::from_secs(1),
+            leniency: 0,
+            history: VecDeque::new(),
+            active: false,
+        }
+    }
+}
+
+impl Sequence {
+    /// Creates an empty sequence, see [Default].
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    fn just_pressed(&mut self, inputs: &Inputs) -> bool {
-        self.iter_mut().any(|binding| binding.just_pressed(inputs))
+    /// Adds a step to the end of the sequence.
+    pub fn then(mut self, step: impl TriggerBinding) -> Self {
+        self.steps.push(Box::new(step));
+        self
     }
 
-    fn just_released(&mut self, inputs: &Inputs) -> bool {
-        self.iter_mut().any(|binding| binding.just_released(inputs))
+    pub fn with_step_window(mut self, step_window: Duration) -> Self {
+        self.step_window = step_window;
+        self
+    }
+
+    pub fn with_total_window(mut self, total_window: Duration) -> Self {
+        self.total_window = total_window;
+        self
+    }
+
+    pub fn with_leniency(mut self, leniency: usize) -> Self {
+        self.leniency = leniency;
+        self
+    }
+
+    /// Returns true if the steps before the last one can be found in the history, in order and within the windows.
+    fn matches(&self, now: Duration) -> bool {
+        let mut end = self.history.len() - 1;
+        let mut anchor = now;
+        let mut skipped = 0;
+
+        for step in (0..self.steps.len() - 1).rev() {
+            let found = self.history.range(..end).rposition(|(time, pressed)| {
+                anchor.saturating_sub(*time) <= self.step_window && pressed.contains(&step)
+            });
+
+            match found {
+                Some(index) => {
+                    end = index;
+                    anchor = self.history[index].0;
+                }
+                None if step > 0 && skipped < self.leniency => skipped += 1,
+                None => return false,
+            }
+        }
+
+        now.saturating_sub(anchor) <= self.total_window
+    }
+}
+
+impl TriggerBinding for Sequence {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+        let now = inputs.time.elapsed();
+        let states = self
+            .steps
+            .iter_mut()
+            .map(|step| step.evaluate(inputs))
+            .collect::<Vec<_>>();
+        let pressed = (0..states.len())
+            .filter(|index| states[*index].just_pressed)
+            .collect::<Vec<_>>();
+
+        self.history
+            .retain(|(time, _)| now.saturating_sub(*time) <= self.total_window);
+        let last = self.steps.len().checked_sub(1);
+        let completed = last.is_some_and(|last| pressed.contains(&last));
+        if !pressed.is_empty() {
+            self.history.push_back((now, pressed));
+        }
+
+        if completed && self.matches(now) {
+            self.history.clear();
+            self.active = true;
+        } else if self.active {
+            self.active = states.last().is_some_and(|state| state.pressed);
+        }
+
+        TriggerState::transition(previous, self.active)
+    }
+
+    fn reset(&mut self) {
+        self.history.clear();
+        self.active = false;
+        for step in &mut self.steps {
+            step.reset();
+        }
     }
 
     fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
         Box::new(self.clone())
     }
 
-    fn all_triggers(&self) -> Vec<Box<dyn TriggerBinding>> {
-        self.iter().map(|b| b.clone_trigger()).collect()
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Sequence)
+            .with_children(self.steps.iter().map(|step| step.describe()).collect())
+            .with_parameter("step window", self.step_window.as_secs_f32())
+            .with_parameter("total window", self.total_window.as_secs_f32())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        self.steps
+            .iter_mut()
+            .map(|step| BindingMut::Trigger(step))
+            .collect()
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Sequence {
+            steps: self
+                .steps
+                .iter()
+                .map(|step| step.to_data())
+                .collect::<Option<_>>()?,
+            step_window: self.step_window,
+            total_window: self.total_window,
+            leniency: self.leniency,
+        })
     }
 }
 
@@ -53,16 +258,8 @@ macro_rules! impl_tuple {
     ($($t:expr),*) => {
         paste! {
             impl<$([<T$t>]: TriggerBinding + Clone),*> TriggerBinding for ($([<T$t>]),*) {
-                fn pressed(&mut self, inputs: &Inputs) -> bool {
-                    false $(|| self.$t.pressed(inputs))*
-                }
-
-                fn just_pressed(&mut self, inputs: &Inputs) -> bool {
-                    false $(|| self.$t.just_pressed(inputs))*
-                }
-
-                fn just_released(&mut self, inputs: &Inputs) -> bool {
-                    false $(|| self.$t.just_released(inputs))*
+                fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+                    TriggerState::any([$(self.$t.evaluate(inputs)),*])
                 }
 
                 fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
@@ -72,6 +269,19 @@ macro_rules! impl_tuple {
                 fn all_triggers(&self) -> Vec<Box<dyn TriggerBinding>> {
                     vec![$(self.$t.clone_trigger()),*]
                 }
+
+                fn to_data(&self) -> Option<TriggerBindingData> {
+                    Some(TriggerBindingData::Any(vec![$(self.$t.to_data()?),*]))
+                }
+
+                fn describe(&self) -> BindingDescription {
+                    BindingDescription::new(BindingKind::Any)
+                        .with_children(vec![$(self.$t.describe()),*])
+                }
+
+                fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+                    vec![$(BindingMut::Trigger(&mut self.$t)),*]
+                }
             }
         }
     };
@@ -86,3 +296,170 @@ impl_tuple!(0, 1, 2, 3, 4, 5, 6);
 impl_tuple!(0, 1, 2, 3, 4, 5, 6, 7);
 impl_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8);
 impl_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+
+/// A logical modifier key that matches both its left and right key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModifierKey {
+    Control,
+    Shift,
+    Alt,
+    Super,
+}
+
+impl ModifierKey {
+    pub const ALL: [ModifierKey; 4] = [
+        ModifierKey::Control,
+        ModifierKey::Shift,
+        ModifierKey::Alt,
+        ModifierKey::Super,
+    ];
+
+    /// Returns the left and right key of this modifier.
+    pub fn keys(&self) -> [KeyCode; 2] {
+        match self {
+            ModifierKey::Control => [KeyCode::ControlLeft, KeyCode::ControlRight],
+            ModifierKey::Shift => [KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            ModifierKey::Alt => [KeyCode::AltLeft, KeyCode::AltRight],
+            ModifierKey::Super => [KeyCode::SuperLeft, KeyCode::SuperRight],
+        }
+    }
+
+    /// Returns the modifier that the given key belongs to, if any.
+    pub fn from_key(key: KeyCode) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|modifier| modifier.keys().contains(&key))
+    }
+
+    /// Returns true if either side of this modifier is pressed.
+    pub fn pressed(&self, inputs: &Inputs) -> bool {
+        inputs.keycodes.any_pressed(self.keys())
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            ModifierKey::Control => "Ctrl",
+            ModifierKey::Shift => "Shift",
+            ModifierKey::Alt => "Alt",
+            ModifierKey::Super => "Super",
+        }
+    }
+}
+
+/// A combinator for keyboard shortcuts like Ctrl+S. Unlike [And], the modifiers match either side, must be held before
+/// the binding is pressed, and can be made exclusive so that Ctrl+Shift+S doesn't also trigger Ctrl+S.
+///
+/// The chord stays pressed until the binding or one of the modifiers is released.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{Chord, ModifierKey, TriggerBinding, testing::MockInputs};
+/// let mut save = Chord::new([ModifierKey::Control], KeyCode::KeyS).exclusive();
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::ControlRight);
+/// mock.with_inputs(|inputs| save.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::KeyS);
+/// assert!(mock.with_inputs(|inputs| save.evaluate(inputs).just_pressed));
+///
+/// // Pressing the modifier after the key doesn't activate the chord.
+/// mock.next_frame().release(KeyCode::ControlRight);
+/// assert!(mock.with_inputs(|inputs| save.evaluate(inputs).just_released));
+/// mock.next_frame().press(KeyCode::ControlLeft);
+/// assert!(!mock.with_inputs(|inputs| save.evaluate(inputs).pressed));
+///
+/// // Extra modifiers are not allowed.
+/// mock.next_frame().release(KeyCode::KeyS).press(KeyCode::ShiftLeft);
+/// mock.with_inputs(|inputs| save.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::KeyS);
+/// assert!(!mock.with_inputs(|inputs| save.evaluate(inputs).pressed));
+///
+/// // Tapping the key within one frame while the modifier is held activates the chord.
+/// mock.next_frame().release(KeyCode::KeyS).release(KeyCode::ShiftLeft);
+/// mock.with_inputs(|inputs| save.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::KeyS).release(KeyCode::KeyS);
+/// let state = mock.with_inputs(|inputs| save.evaluate(inputs));
+/// assert!(state.just_pressed && state.just_released);
+/// ```
+#[derive(Clone)]
+pub struct Chord<T: TriggerBinding> {
+    pub modifiers: Vec<ModifierKey>,
+    pub binding: T,
+    /// If true, the chord doesn't activate while modifiers that are not part of it are held.
+    pub exclusive: bool,
+    armed: bool,
+}
+
+impl<T: TriggerBinding> Chord<T> {
+    pub fn new(modifiers: impl IntoIterator<Item = ModifierKey>, binding: T) -> Self {
+        Self {
+            modifiers: modifiers.into_iter().collect(),
+            binding,
+            exclusive: false,
+            armed: false,
+        }
+    }
+
+    /// Returns the chord with [exclusive](Self::exclusive) set.
+    pub fn exclusive(mut self) -> Self {
+        self.exclusive = true;
+        self
+    }
+
+    /// Returns true if exactly the required modifiers are held, or at least them if the chord isn't exclusive.
+    fn modifiers_held(&self, inputs: &Inputs) -> bool {
+        ModifierKey::ALL.iter().all(|modifier| {
+            let required = self.modifiers.contains(modifier);
+            let pressed = modifier.pressed(inputs);
+            pressed == required || (!required && !self.exclusive)
+        })
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for Chord<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.armed;
+        let state = self.binding.evaluate(inputs);
+        let modifiers_held = self.modifiers_held(inputs);
+        let activated = state.just_pressed && modifiers_held;
+        if state.just_pressed {
+            self.armed = modifiers_held;
+        }
+        self.armed &= modifiers_held && state.pressed;
+
+        TriggerState::transition(previous, self.armed).with_tap(activated && !self.armed)
+    }
+
+    fn reset(&mut self) {
+        self.armed = false;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        let modifiers = self.modifiers.iter().map(|modifier| {
+            let keys = modifier.keys().map(BindingDescription::input);
+            BindingDescription::named(modifier.name()).with_children(keys.to_vec())
+        });
+
+        BindingDescription::new(BindingKind::All)
+            .with_children(modifiers.chain([self.binding.describe()]).collect())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Chord {
+            modifiers: self.modifiers.clone(),
+            binding: Box::new(self.binding.to_data()?),
+            exclusive: self.exclusive,
+        })
+    }
+}
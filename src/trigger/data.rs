@@ -0,0 +1,201 @@
+use crate::{
+    And, AxisBindingData, AxisThreshold, Chord, Cooldown, Debounce, DualAxisBindingData,
+    DualAxisDirection, GamepadPolicy, Hold, LongPress, ModifierKey, MultiTap, Not, OnGamepad,
+    Repeat, Sequence, Tap, Toggle, TriggerBinding,
+};
+use bevy::{
+    input::{gamepad::GamepadButton, keyboard::KeyCode, mouse::MouseButton},
+    math::CompassOctant,
+};
+use std::time::Duration;
+
+/// A plain data description of a trigger binding. Every built-in trigger binding can be converted to this type with
+/// [TriggerBinding::to_data] and back with [TriggerBindingData::into_binding], except for an [OnGamepad](crate::OnGamepad)
+/// scoped to a specific gamepad entity.
+///
+/// With the `serialize` feature enabled, this type implements `Serialize` and `Deserialize`, so bindings can be stored
+/// in any serde format.
+///
+/// Tuples and vecs are both described as [TriggerBindingData::Any].
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingData};
+/// let binding = (KeyCode::Space, GamepadButton::South);
+/// let data = binding.to_data().unwrap();
+///
+/// assert_eq!(
+///     data,
+///     TriggerBindingData::Any(vec![
+///         TriggerBindingData::Key(KeyCode::Space),
+///         TriggerBindingData::GamepadButton(GamepadButton::South),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerBindingData {
+    None,
+    Constant(bool),
+    Key(KeyCode),
+    MouseButton(MouseButton),
+    GamepadButton(GamepadButton),
+    Any(Vec<TriggerBindingData>),
+    And(Box<TriggerBindingData>, Box<TriggerBindingData>),
+    Not(Box<TriggerBindingData>),
+    AxisThreshold {
+        binding: Box<AxisBindingData>,
+        press_at: f32,
+        release_at: f32,
+    },
+    Toggle {
+        binding: Box<TriggerBindingData>,
+        auto_release: Option<Duration>,
+    },
+    Cooldown {
+        binding: Box<TriggerBindingData>,
+        duration: Duration,
+    },
+    Debounce {
+        binding: Box<TriggerBindingData>,
+        duration: Duration,
+    },
+    Repeat {
+        binding: Box<TriggerBindingData>,
+        initial_delay: Duration,
+        interval: Duration,
+    },
+    Hold {
+        binding: Box<TriggerBindingData>,
+        duration: Duration,
+    },
+    Tap {
+        binding: Box<TriggerBindingData>,
+        max_duration: Duration,
+    },
+    LongPress {
+        binding: Box<TriggerBindingData>,
+        duration: Duration,
+    },
+    MultiTap {
+        binding: Box<TriggerBindingData>,
+        count: u32,
+        max_interval: Duration,
+    },
+    Sequence {
+        steps: Vec<TriggerBindingData>,
+        step_window: Duration,
+        total_window: Duration,
+        leniency: usize,
+    },
+    Chord {
+        modifiers: Vec<ModifierKey>,
+        binding: Box<TriggerBindingData>,
+        exclusive: bool,
+    },
+    OnGamepad(Box<TriggerBindingData>, GamepadPolicy),
+    DualAxisDirection {
+        binding: Box<DualAxisBindingData>,
+        direction: CompassOctant,
+        threshold: f32,
+    },
+}
+
+impl TriggerBindingData {
+    /// Builds the binding described by this data.
+    pub fn into_binding(self) -> Box<dyn TriggerBinding> {
+        match self {
+            TriggerBindingData::None => Box::new(()),
+            TriggerBindingData::Constant(value) => Box::new(value),
+            TriggerBindingData::Key(key) => Box::new(key),
+            TriggerBindingData::MouseButton(button) => Box::new(button),
+            TriggerBindingData::GamepadButton(button) => Box::new(button),
+            TriggerBindingData::Any(all) => Box::new(
+                all.into_iter()
+                    .map(TriggerBindingData::into_binding)
+                    .collect::<Vec<_>>(),
+            ),
+            TriggerBindingData::And(a, b) => Box::new(And(a.into_binding(), b.into_binding())),
+            TriggerBindingData::Not(a) => Box::new(Not(a.into_binding())),
+            TriggerBindingData::AxisThreshold {
+                binding,
+                press_at,
+                release_at,
+            } => Box::new(AxisThreshold::new(
+                binding.into_binding(),
+                press_at,
+                release_at,
+            )),
+            TriggerBindingData::Toggle {
+                binding,
+                auto_release,
+            } => {
+                let mut toggle = Toggle::new(binding.into_binding());
+                toggle.auto_release = auto_release;
+                Box::new(toggle)
+            }
+            TriggerBindingData::Cooldown { binding, duration } => {
+                Box::new(Cooldown::new(binding.into_binding(), duration))
+            }
+            TriggerBindingData::Debounce { binding, duration } => {
+                Box::new(Debounce::new(binding.into_binding(), duration))
+            }
+            TriggerBindingData::Repeat {
+                binding,
+                initial_delay,
+                interval,
+            } => Box::new(Repeat::new(binding.into_binding(), initial_delay, interval)),
+            TriggerBindingData::Hold { binding, duration } => {
+                Box::new(Hold::new(binding.into_binding(), duration))
+            }
+            TriggerBindingData::Tap {
+                binding,
+                max_duration,
+            } => Box::new(Tap::new(binding.into_binding(), max_duration)),
+            TriggerBindingData::LongPress { binding, duration } => {
+                Box::new(LongPress::new(binding.into_binding(), duration))
+            }
+            TriggerBindingData::MultiTap {
+                binding,
+                count,
+                max_interval,
+            } => Box::new(MultiTap::new(binding.into_binding(), count, max_interval)),
+            TriggerBindingData::Sequence {
+                steps,
+                step_window,
+                total_window,
+                leniency,
+            } => {
+                let mut sequence = Sequence::new()
+                    .with_step_window(step_window)
+                    .with_total_window(total_window)
+                    .with_leniency(leniency);
+                sequence.steps = steps
+                    .into_iter()
+                    .map(TriggerBindingData::into_binding)
+                    .collect();
+                Box::new(sequence)
+            }
+            TriggerBindingData::Chord {
+                modifiers,
+                binding,
+                exclusive,
+            } => {
+                let mut chord = Chord::new(modifiers, binding.into_binding());
+                chord.exclusive = exclusive;
+                Box::new(chord)
+            }
+            TriggerBindingData::OnGamepad(binding, policy) => {
+                Box::new(OnGamepad(binding.into_binding(), policy))
+            }
+            TriggerBindingData::DualAxisDirection {
+                binding,
+                direction,
+                threshold,
+            } => Box::new(
+                DualAxisDirection::new(binding.into_binding(), direction).with_threshold(threshold),
+            ),
+        }
+    }
+}
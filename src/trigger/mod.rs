@@ -1,17 +1,144 @@
-use crate::inputs::Inputs;
-use bevy::ecs::resource::Resource;
+use crate::{
+    clock::InputClock,
+    context::InputContext,
+    describe::{BindingDescription, BindingMut, InputMut},
+    gamepad::GamepadPolicy,
+    inputs::Inputs,
+    registry::{ActionState, RegistryAction},
+    trigger::data::TriggerBindingData,
+};
+use bevy::{
+    ecs::{component::Component, resource::Resource},
+    utils::prelude::ShortName,
+};
 use dyn_clone::DynClone;
-use std::{any::Any, marker::PhantomData};
+use std::{any::Any, marker::PhantomData, time::Duration};
 
 mod bindings;
 pub mod builder;
 pub mod combinators;
+pub mod data;
 pub mod modifiers;
 
+/// The state of a trigger binding in one frame, returned by [TriggerBinding::evaluate].
+///
+/// The edges describe the change since the previous frame, so a binding that is pressed this frame but wasn't in the
+/// previous one is `just_pressed`. An input that is pressed and released within the same frame is both `just_pressed`
+/// and `just_released`, but not `pressed`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TriggerState {
+    pub pressed: bool,
+    pub just_pressed: bool,
+    pub just_released: bool,
+    /// How far a timed binding like [Hold](crate::Hold) is towards activating, from 0.0 to 1.0. Bindings that aren't
+    /// timed report `None`.
+    pub progress: Option<f32>,
+}
+
+impl TriggerState {
+    /// Returns the state of a binding that was `previous` in the previous frame and is `pressed` now.
+    ///
+    /// # Examples
+    /// ```
+    /// # use press_here::TriggerState;
+    /// let state = TriggerState::transition(false, true);
+    /// assert!(state.pressed && state.just_pressed && !state.just_released);
+    /// assert!(!state.previous());
+    /// ```
+    pub fn transition(previous: bool, pressed: bool) -> Self {
+        Self {
+            pressed,
+            just_pressed: pressed && !previous,
+            just_released: !pressed && previous,
+            progress: None,
+        }
+    }
+
+    /// Returns the state with both edges set if `tapped`, e.g. for a modifier that activated and released within the
+    /// same frame.
+    ///
+    /// # Examples
+    /// ```
+    /// # use press_here::TriggerState;
+    /// let state = TriggerState::transition(false, false).with_tap(true);
+    /// assert!(!state.pressed && state.just_pressed && state.just_released);
+    /// ```
+    pub fn with_tap(mut self, tapped: bool) -> Self {
+        self.just_pressed |= tapped;
+        self.just_released |= tapped;
+        self
+    }
+
+    /// Returns the state with the given progress.
+    pub fn with_progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Returns whether the binding was pressed in the previous frame, derived from the edges.
+    pub fn previous(&self) -> bool {
+        if self.pressed {
+            !self.just_pressed || self.just_released
+        } else {
+            self.just_released && !self.just_pressed
+        }
+    }
+
+    /// Combines the states of several bindings, pressed while any of them is. A press and release within the same
+    /// frame is kept.
+    pub fn any(states: impl IntoIterator<Item = TriggerState>) -> Self {
+        let (mut previous, mut pressed, mut tapped) = (false, false, false);
+        let mut progress = None::<f32>;
+        for state in states {
+            previous |= state.previous();
+            pressed |= state.pressed;
+            tapped |= state.just_pressed && state.just_released;
+            progress = match (progress, state.progress) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                (a, b) => a.or(b),
+            };
+        }
+
+        let mut state = Self::transition(previous, pressed);
+        if !previous && !pressed && tapped {
+            state.just_pressed = true;
+            state.just_released = true;
+        }
+        state.progress = progress;
+        state
+    }
+}
+
+/// A binding that can be pressed and released, like a key or a combination of keys.
 pub trait TriggerBinding: DynClone + Any + Send + Sync + 'static {
-    fn pressed(&mut self, inputs: &Inputs) -> bool;
-    fn just_pressed(&mut self, inputs: &Inputs) -> bool;
-    fn just_released(&mut self, inputs: &Inputs) -> bool;
+    /// Evaluates the binding for the current frame. This is called exactly once per frame, so stateful bindings can
+    /// advance their state here and derive the edges from the state of the previous frame, see
+    /// [TriggerState::transition].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+    /// let mut binding = KeyCode::Space.not();
+    /// let mut mock = MockInputs::new();
+    ///
+    /// mock.with_inputs(|inputs| binding.evaluate(inputs));
+    /// mock.next_frame().press(KeyCode::Space);
+    /// assert!(mock.with_inputs(|inputs| binding.evaluate(inputs).just_released));
+    ///
+    /// mock.next_frame();
+    /// let state = mock.with_inputs(|inputs| binding.evaluate(inputs));
+    /// assert!(!state.pressed && !state.just_pressed && !state.just_released);
+    /// ```
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState;
+
+    /// Clears the state of a stateful binding, e.g. a [Toggle](crate::Toggle) or the timer of a [Hold](crate::Hold).
+    /// This is called while the trigger's context is inactive. The default implementation resets the children.
+    fn reset(&mut self) {
+        for child in self.children_mut() {
+            child.reset();
+        }
+    }
 
     /// Clones the inner value and returns it as a boxed trait object.
     fn clone_trigger(&self) -> Box<dyn TriggerBinding>;
@@ -45,11 +172,33 @@ pub trait TriggerBinding: DynClone + Any + Send + Sync + 'static {
     fn all_triggers(&self) -> Vec<Box<dyn TriggerBinding>> {
         vec![self.clone_trigger()]
     }
+
+    /// Returns a plain data description of this binding, or `None` if this binding (or any of its children) can't be
+    /// described as data. See [TriggerBindingData].
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        None
+    }
+
+    /// Returns a structured, human-readable description of this binding. See [BindingDescription].
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::named(ShortName::of::<Self>().to_string())
+    }
+
+    /// Returns mutable references to the direct children of this binding. Leaf bindings return an empty vec.
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        Vec::new()
+    }
+
+    /// Returns a mutable reference to the physical input if this binding is a single input, e.g. a key.
+    fn input_mut(&mut self) -> Option<InputMut<'_>> {
+        None
+    }
 }
 
 dyn_clone::clone_trait_object!(TriggerBinding);
 
-/// A resource that holds the current state and binding for a trigger.
+/// A resource that holds the current state and binding for a trigger. It can also be used as a component, see
+/// [`AppExt::add_trigger_component`](crate::AppExt::add_trigger_component).
 ///
 /// # Examples
 /// Reading the state:
@@ -70,13 +219,23 @@ dyn_clone::clone_trait_object!(TriggerBinding);
 ///    trigger.set_binding(KeyCode::Space);
 /// }
 /// ```
-#[derive(Resource)]
+#[derive(Resource, Component)]
 pub struct Trigger<T> {
     trigger: PhantomData<T>,
     pub(crate) pressed: bool,
     pub(crate) just_pressed: bool,
     pub(crate) just_released: bool,
+    pub(crate) previous_pressed: bool,
+    /// True if the state was overridden after the last update, so the binding's edges don't match the reported state.
+    pub(crate) interrupted: bool,
+    pub(crate) progress: f32,
+    pub(crate) now: Duration,
+    pub(crate) pressed_at: Option<Duration>,
     pub(crate) binding: Box<dyn TriggerBinding>,
+    pub(crate) gamepad: GamepadPolicy,
+    pub(crate) clock: Option<InputClock>,
+    pub(crate) context: Option<InputContext>,
+    pub(crate) priority: i32,
 }
 
 impl<T> Trigger<T> {
@@ -86,10 +245,25 @@ impl<T> Trigger<T> {
             pressed: false,
             just_pressed: false,
             just_released: false,
+            previous_pressed: false,
+            interrupted: false,
+            progress: 0.0,
+            now: Duration::ZERO,
+            pressed_at: None,
             binding: Box::new(binding),
+            gamepad: GamepadPolicy::Any,
+            clock: None,
+            context: None,
+            priority: 0,
         }
     }
 
+    /// Returns the trigger with its binding scoped to the gamepads allowed by the given policy.
+    pub fn with_gamepad(mut self, policy: GamepadPolicy) -> Self {
+        self.gamepad = policy;
+        self
+    }
+
     pub fn pressed(&self) -> bool {
         self.pressed
     }
@@ -102,6 +276,64 @@ impl<T> Trigger<T> {
         self.just_released
     }
 
+    /// Returns how far the binding is towards activating, from 0.0 to 1.0. For timed bindings like [Hold](crate::Hold)
+    /// this is the fraction of the duration that has been held. [Cooldown](crate::Cooldown) reports how far it has
+    /// recovered instead, which is 1.0 while it's ready. Other bindings report 1.0 while pressed and 0.0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, Trigger, TriggerBindingBuilder, testing::MockAppExt};
+    /// # use std::time::Duration;
+    /// let mut app = App::new();
+    /// app.init_mock_inputs().add_trigger::<Interact>(KeyCode::KeyE.hold(Duration::from_secs(2)));
+    ///
+    /// app.press(KeyCode::KeyE).update();
+    /// app.advance(Duration::from_millis(500)).update();
+    /// assert_eq!(app.world().resource::<Trigger<Interact>>().progress(), 0.25);
+    ///
+    /// # struct Interact;
+    /// ```
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Returns true if the trigger was just pressed within the given window and the press hasn't been consumed, e.g. to
+    /// still jump if the button was pressed shortly before landing. Time is read from the trigger's clock, see
+    /// [with_clock](Self::with_clock).
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{AppExt, Trigger, testing::MockAppExt};
+    /// # use std::time::Duration;
+    /// let mut app = App::new();
+    /// app.init_mock_inputs().add_trigger::<Jump>(KeyCode::Space);
+    ///
+    /// app.press(KeyCode::Space).update();
+    /// app.release(KeyCode::Space).advance(Duration::from_millis(80)).update();
+    ///
+    /// let mut jump = app.world_mut().resource_mut::<Trigger<Jump>>();
+    /// assert!(jump.pressed_within(Duration::from_millis(100)));
+    /// assert!(!jump.pressed_within(Duration::from_millis(50)));
+    ///
+    /// // The player landed, so the buffered press is used up.
+    /// jump.consume();
+    /// assert!(!jump.pressed_within(Duration::from_millis(100)));
+    ///
+    /// # struct Jump;
+    /// ```
+    pub fn pressed_within(&self, window: Duration) -> bool {
+        self.pressed_at
+            .is_some_and(|at| self.now.saturating_sub(at) <= window)
+    }
+
+    /// Forgets the last press, so [pressed_within](Self::pressed_within) returns false until the trigger is pressed
+    /// again. Doesn't change the current state of the trigger.
+    pub fn consume(&mut self) {
+        self.pressed_at = None;
+    }
+
     pub fn binding(&self) -> &dyn TriggerBinding {
         self.binding.as_ref()
     }
@@ -109,4 +341,173 @@ impl<T> Trigger<T> {
     pub fn set_binding(&mut self, binding: impl TriggerBinding) {
         self.binding = Box::new(binding);
     }
+
+    /// Replaces one slot of the binding. The binding is split with [TriggerBinding::all_triggers] and the slot at the
+    /// given index is replaced. If the index is out of bounds, the new binding is added as an extra slot.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use press_here::{Trigger, TriggerBinding};
+    /// # struct MyTrigger;
+    /// let mut trigger = Trigger::<MyTrigger>::new((KeyCode::Space, GamepadButton::South));
+    /// trigger.replace_slot(0, KeyCode::Enter);
+    ///
+    /// let slots = trigger.binding().all_triggers();
+    /// assert_eq!(slots[0].as_any().downcast_ref::<KeyCode>(), Some(&KeyCode::Enter));
+    /// assert_eq!(slots[1].as_any().downcast_ref::<GamepadButton>(), Some(&GamepadButton::South));
+    /// ```
+    pub fn replace_slot(&mut self, slot: usize, binding: impl TriggerBinding) {
+        let mut all = self.binding.as_ref().all_triggers();
+        let binding = Box::new(binding) as Box<dyn TriggerBinding>;
+
+        if let Some(existing) = all.get_mut(slot) {
+            *existing = binding;
+        } else {
+            all.push(binding);
+        }
+
+        self.set_binding(all);
+    }
+
+    pub fn gamepad(&self) -> GamepadPolicy {
+        self.gamepad
+    }
+
+    pub fn set_gamepad(&mut self, policy: GamepadPolicy) {
+        self.gamepad = policy;
+    }
+
+    /// Returns the trigger with time-based bindings reading from the given clock instead of the app default.
+    pub fn with_clock(mut self, clock: InputClock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// The clock set with [with_clock](Self::with_clock), or `None` if the app default is used.
+    pub fn clock(&self) -> Option<InputClock> {
+        self.clock
+    }
+
+    pub fn set_clock(&mut self, clock: Option<InputClock>) {
+        self.clock = clock;
+    }
+
+    /// Returns the trigger assigned to the given context. It's only evaluated while the context is active, see
+    /// [InputContexts](crate::InputContexts).
+    pub fn with_context(mut self, context: InputContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// The context set with [with_context](Self::with_context), or `None` if the trigger is always active.
+    pub fn context(&self) -> Option<InputContext> {
+        self.context
+    }
+
+    pub fn set_context(&mut self, context: Option<InputContext>) {
+        self.context = context;
+    }
+
+    /// Returns the trigger with the given priority. When input consumption is enabled, an active action suppresses
+    /// actions with a lower priority that share an input, see
+    /// [PressHerePlugin::with_input_consumption](crate::PressHerePlugin::with_input_consumption). Defaults to 0.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    /// Resets the trigger to unpressed without evaluating the binding, e.g. while its context is inactive. The binding is
+    /// reset too, so it starts fresh once the context is active again, and the last press is forgotten.
+    pub(crate) fn deactivate(&mut self) {
+        self.binding.reset();
+        self.interrupted = true;
+        self.pressed_at = None;
+        self.previous_pressed = self.pressed;
+        self.just_pressed = false;
+        self.just_released = self.pressed;
+        self.pressed = false;
+        self.progress = 0.0;
+    }
+
+    /// Resets the trigger to unpressed after it was evaluated, because a higher-priority action consumed its input. The
+    /// last press is forgotten, so a buffered press doesn't outlive the suppression.
+    pub(crate) fn suppress(&mut self) {
+        self.pressed_at = None;
+        self.just_pressed = false;
+        self.just_released = self.previous_pressed;
+        self.pressed = false;
+        self.progress = 0.0;
+        self.interrupted = true;
+    }
+
+    pub(crate) fn update(&mut self, inputs: &Inputs) {
+        let (binding, gamepad) = (&mut self.binding, self.gamepad);
+        let mut evaluate = |inputs: &Inputs| {
+            let state = inputs.with_gamepad_policy(gamepad, |inputs| binding.evaluate(inputs));
+            (state, inputs.time.elapsed())
+        };
+
+        let (state, now) = match self.clock {
+            Some(clock) => inputs.with_clock(clock, evaluate),
+            None => evaluate(inputs),
+        };
+
+        // The binding's edges are relative to a state that wasn't reported, so derive them from the reported one.
+        let edges = if self.interrupted {
+            TriggerState::transition(self.pressed, state.pressed)
+        } else {
+            state
+        };
+        self.interrupted = false;
+
+        self.now = now;
+        if edges.just_pressed {
+            self.pressed_at = Some(now);
+        }
+
+        self.previous_pressed = self.pressed;
+        self.pressed = state.pressed;
+        self.just_pressed = edges.just_pressed;
+        self.just_released = edges.just_released;
+        self.progress = state
+            .progress
+            .unwrap_or(if state.pressed { 1.0 } else { 0.0 });
+    }
+}
+
+impl<T: Send + Sync + 'static> RegistryAction for Trigger<T> {
+    type Binding = dyn TriggerBinding;
+
+    fn binding(&self) -> &Self::Binding {
+        self.binding.as_ref()
+    }
+
+    fn set_binding(&mut self, binding: Box<Self::Binding>) {
+        self.binding = binding;
+    }
+
+    fn state(&self) -> ActionState {
+        ActionState {
+            active: self.pressed || self.just_pressed,
+            priority: self.priority,
+            context: self.context,
+        }
+    }
+
+    fn describe(&self) -> BindingDescription {
+        self.binding.describe()
+    }
+
+    fn suppress(&mut self) {
+        self.suppress();
+    }
 }
@@ -1,23 +1,880 @@
-use crate::{TriggerBinding, inputs::Inputs};
+use crate::{
+    AxisBinding, BindingDescription, BindingKind, BindingMut, TriggerBinding, TriggerBindingData,
+    TriggerState, inputs::Inputs,
+};
+use std::time::Duration;
 
 /// A modifier that inverts the trigger state.
 #[derive(Clone, Copy)]
 pub struct Not<T: TriggerBinding>(pub T);
 
 impl<T: TriggerBinding + Clone> TriggerBinding for Not<T> {
-    fn pressed(&mut self, inputs: &Inputs) -> bool {
-        !self.0.pressed(inputs)
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let state = self.0.evaluate(inputs);
+        TriggerState::transition(!state.previous(), !state.pressed)
     }
 
-    fn just_pressed(&mut self, inputs: &Inputs) -> bool {
-        !self.0.just_pressed(inputs)
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::new(BindingKind::Prefix("not".into()))
+            .with_children(vec![self.0.describe()])
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.0)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Not(Box::new(self.0.to_data()?)))
+    }
+}
+
+/// Tracks when the inner binding of a timed modifier was pressed.
+#[derive(Clone, Copy, Default)]
+struct PressTimer {
+    pressed_at: Option<Duration>,
+}
+
+impl PressTimer {
+    /// Returns how long the binding has been held, or `None` if it's neither held nor released this frame.
+    fn held(&mut self, state: TriggerState, inputs: &Inputs) -> Option<Duration> {
+        let now = inputs.time.elapsed();
+        if state.just_pressed {
+            self.pressed_at = Some(now);
+        } else if state.pressed {
+            self.pressed_at.get_or_insert(now);
+        }
+
+        let held = self.pressed_at.map(|at| now.saturating_sub(at));
+        if !state.pressed {
+            self.pressed_at = None;
+        }
+        held
     }
+}
+
+/// Returns how far `held` is towards `duration`, from 0.0 to 1.0.
+fn fraction(held: Duration, duration: Duration) -> f32 {
+    if duration.is_zero() {
+        return 1.0;
+    }
+    (held.as_secs_f32() / duration.as_secs_f32()).min(1.0)
+}
+
+/// A modifier that is only pressed after the binding has been held for the given duration, e.g. "hold E to interact".
+/// It stays pressed until the binding is released. Reports its progress towards the duration, see
+/// [Trigger::progress](crate::Trigger::progress).
+///
+/// Time is read from the action's clock, like [Smooth](crate::Smooth).
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+/// # use std::time::Duration;
+/// let mut binding = KeyCode::KeyE.hold(Duration::from_secs(1));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::KeyE);
+/// assert!(!mock.with_inputs(|inputs| binding.evaluate(inputs).pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(500));
+/// assert_eq!(mock.with_inputs(|inputs| binding.evaluate(inputs).progress), Some(0.5));
+///
+/// mock.next_frame().advance(Duration::from_millis(500));
+/// assert!(mock.with_inputs(|inputs| binding.evaluate(inputs).just_pressed));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Hold<T: TriggerBinding> {
+    pub binding: T,
+    pub duration: Duration,
+    timer: PressTimer,
+    active: bool,
+}
 
-    fn just_released(&mut self, inputs: &Inputs) -> bool {
-        !self.0.just_released(inputs)
+impl<T: TriggerBinding> Hold<T> {
+    pub fn new(binding: T, duration: Duration) -> Self {
+        Self {
+            binding,
+            duration,
+            timer: PressTimer::default(),
+            active: false,
+        }
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for Hold<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+        let state = self.binding.evaluate(inputs);
+        let held = self.timer.held(state, inputs).filter(|_| state.pressed);
+        self.active = held.is_some_and(|held| held >= self.duration);
+
+        TriggerState::transition(previous, self.active)
+            .with_progress(held.map_or(0.0, |held| fraction(held, self.duration)))
+    }
+
+    fn reset(&mut self) {
+        self.timer = PressTimer::default();
+        self.active = false;
+        self.binding.reset();
     }
 
     fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
         Box::new(self.clone())
     }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("hold", vec![self.binding.describe()])
+            .with_parameter("duration", self.duration.as_secs_f32())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Hold {
+            binding: Box::new(self.binding.to_data()?),
+            duration: self.duration,
+        })
+    }
+}
+
+/// A modifier that is pressed for a single frame when the binding is released within the given duration of being
+/// pressed, and released in the frame after. Combine it with [LongPress] to do different things on tap and on hold.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+/// # use std::time::Duration;
+/// let mut binding = KeyCode::ShiftLeft.tap(Duration::from_millis(200));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::ShiftLeft);
+/// assert!(!mock.with_inputs(|inputs| binding.evaluate(inputs).pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(100)).release(KeyCode::ShiftLeft);
+/// assert!(mock.with_inputs(|inputs| binding.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame();
+/// assert!(mock.with_inputs(|inputs| binding.evaluate(inputs).just_released));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Tap<T: TriggerBinding> {
+    pub binding: T,
+    pub max_duration: Duration,
+    timer: PressTimer,
+    active: bool,
+}
+
+impl<T: TriggerBinding> Tap<T> {
+    pub fn new(binding: T, max_duration: Duration) -> Self {
+        Self {
+            binding,
+            max_duration,
+            timer: PressTimer::default(),
+            active: false,
+        }
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for Tap<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+        let state = self.binding.evaluate(inputs);
+        let held = self.timer.held(state, inputs);
+        self.active = state.just_released && held.is_some_and(|held| held <= self.max_duration);
+
+        TriggerState::transition(previous, self.active)
+    }
+
+    fn reset(&mut self) {
+        self.timer = PressTimer::default();
+        self.active = false;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("tap", vec![self.binding.describe()])
+            .with_parameter("max duration", self.max_duration.as_secs_f32())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Tap {
+            binding: Box::new(self.binding.to_data()?),
+            max_duration: self.max_duration,
+        })
+    }
+}
+
+/// A modifier that is pressed for a single frame when the binding is released after being held for at least the given
+/// duration. Unlike [Hold], it doesn't activate until the binding is released, so it can be combined with [Tap] on the
+/// same input. Reports its progress while the binding is held.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+/// # use std::time::Duration;
+/// let mut binding = KeyCode::KeyR.long_press(Duration::from_secs(1));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::KeyR);
+/// assert!(!mock.with_inputs(|inputs| binding.evaluate(inputs).pressed));
+///
+/// mock.next_frame().advance(Duration::from_secs(2));
+/// assert_eq!(mock.with_inputs(|inputs| binding.evaluate(inputs).progress), Some(1.0));
+///
+/// mock.next_frame().release(KeyCode::KeyR);
+/// assert!(mock.with_inputs(|inputs| binding.evaluate(inputs).just_pressed));
+/// ```
+#[derive(Clone, Copy)]
+pub struct LongPress<T: TriggerBinding> {
+    pub binding: T,
+    pub duration: Duration,
+    timer: PressTimer,
+    active: bool,
+}
+
+impl<T: TriggerBinding> LongPress<T> {
+    pub fn new(binding: T, duration: Duration) -> Self {
+        Self {
+            binding,
+            duration,
+            timer: PressTimer::default(),
+            active: false,
+        }
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for LongPress<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+        let state = self.binding.evaluate(inputs);
+        let held = self.timer.held(state, inputs);
+        self.active = state.just_released && held.is_some_and(|held| held >= self.duration);
+
+        let progress = held
+            .filter(|_| state.pressed)
+            .map_or(0.0, |held| fraction(held, self.duration));
+        TriggerState::transition(previous, self.active).with_progress(progress)
+    }
+
+    fn reset(&mut self) {
+        self.timer = PressTimer::default();
+        self.active = false;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("long press", vec![self.binding.describe()])
+            .with_parameter("duration", self.duration.as_secs_f32())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::LongPress {
+            binding: Box::new(self.binding.to_data()?),
+            duration: self.duration,
+        })
+    }
+}
+
+/// A modifier that activates when the binding is pressed `count` times, with at most `max_interval` between each press,
+/// e.g. a double-tap to dash. It's `just_pressed` on the last press and stays pressed until that press is released.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+/// # use std::time::Duration;
+/// let mut binding = KeyCode::KeyD.multi_tap(2, Duration::from_millis(300));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::KeyD);
+/// assert!(!mock.with_inputs(|inputs| binding.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(100)).release(KeyCode::KeyD);
+/// mock.with_inputs(|inputs| binding.evaluate(inputs));
+///
+/// mock.next_frame().advance(Duration::from_millis(100)).press(KeyCode::KeyD);
+/// assert!(mock.with_inputs(|inputs| binding.evaluate(inputs).just_pressed));
+///
+/// // The last tap can be pressed and released within one frame.
+/// mock.next_frame().advance(Duration::from_secs(1)).release(KeyCode::KeyD);
+/// mock.with_inputs(|inputs| binding.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::KeyD).release(KeyCode::KeyD);
+/// mock.with_inputs(|inputs| binding.evaluate(inputs));
+/// mock.next_frame().advance(Duration::from_millis(100)).press(KeyCode::KeyD).release(KeyCode::KeyD);
+/// let state = mock.with_inputs(|inputs| binding.evaluate(inputs));
+/// assert!(state.just_pressed && state.just_released && !state.pressed);
+/// ```
+#[derive(Clone, Copy)]
+pub struct MultiTap<T: TriggerBinding> {
+    pub binding: T,
+    pub count: u32,
+    pub max_interval: Duration,
+    taps: u32,
+    last_press: Option<Duration>,
+    active: bool,
+}
+
+impl<T: TriggerBinding> MultiTap<T> {
+    pub fn new(binding: T, count: u32, max_interval: Duration) -> Self {
+        Self {
+            binding,
+            count,
+            max_interval,
+            taps: 0,
+            last_press: None,
+            active: false,
+        }
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for MultiTap<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+        let state = self.binding.evaluate(inputs);
+        let now = inputs.time.elapsed();
+        let mut completed = false;
+        if state.just_pressed {
+            let within = self
+                .last_press
+                .is_some_and(|at| now.saturating_sub(at) <= self.max_interval);
+            self.taps = if within { self.taps + 1 } else { 1 };
+            self.last_press = Some(now);
+
+            if self.taps >= self.count {
+                self.taps = 0;
+                self.active = true;
+                completed = true;
+            }
+        }
+        self.active &= state.pressed;
+
+        TriggerState::transition(previous, self.active).with_tap(completed && !self.active)
+    }
+
+    fn reset(&mut self) {
+        self.taps = 0;
+        self.last_press = None;
+        self.active = false;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("multi tap", vec![self.binding.describe()])
+            .with_parameter("count", self.count as f32)
+            .with_parameter("max interval", self.max_interval.as_secs_f32())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::MultiTap {
+            binding: Box::new(self.binding.to_data()?),
+            count: self.count,
+            max_interval: self.max_interval,
+        })
+    }
+}
+
+/// A modifier that flips between pressed and released each time the binding is pressed, e.g. "press to toggle crouch"
+/// as an accessibility setting. If `auto_release` is set, the modifier also releases by itself after that duration.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+/// let mut crouch = KeyCode::KeyC.toggle();
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::KeyC);
+/// assert!(mock.with_inputs(|inputs| crouch.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame().release(KeyCode::KeyC);
+/// assert!(mock.with_inputs(|inputs| crouch.evaluate(inputs).pressed));
+///
+/// mock.next_frame().press(KeyCode::KeyC);
+/// assert!(mock.with_inputs(|inputs| crouch.evaluate(inputs).just_released));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Toggle<T: TriggerBinding> {
+    pub binding: T,
+    pub auto_release: Option<Duration>,
+    toggled_at: Option<Duration>,
+}
+
+impl<T: TriggerBinding> Toggle<T> {
+    pub fn new(binding: T) -> Self {
+        Self {
+            binding,
+            auto_release: None,
+            toggled_at: None,
+        }
+    }
+
+    /// Returns the modifier with [auto_release](Self::auto_release) set.
+    pub fn with_auto_release(mut self, duration: Duration) -> Self {
+        self.auto_release = Some(duration);
+        self
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for Toggle<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.toggled_at.is_some();
+        let now = inputs.time.elapsed();
+        if self.binding.evaluate(inputs).just_pressed {
+            self.toggled_at = if previous { None } else { Some(now) };
+        }
+
+        let expired = self
+            .toggled_at
+            .zip(self.auto_release)
+            .is_some_and(|(at, duration)| now.saturating_sub(at) >= duration);
+        if expired {
+            self.toggled_at = None;
+        }
+
+        TriggerState::transition(previous, self.toggled_at.is_some())
+    }
+
+    fn reset(&mut self) {
+        self.toggled_at = None;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        let description = BindingDescription::modifier("toggle", vec![self.binding.describe()]);
+        match self.auto_release {
+            Some(duration) => description.with_parameter("auto release", duration.as_secs_f32()),
+            None => description,
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Toggle {
+            binding: Box::new(self.binding.to_data()?),
+            auto_release: self.auto_release,
+        })
+    }
+}
+
+/// A modifier that ignores presses of the binding for the given duration after it was last activated, e.g. an ability
+/// that can only be used once per second. Reports how far the cooldown has recovered as its progress.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+/// # use std::time::Duration;
+/// let mut dash = KeyCode::ShiftLeft.cooldown(Duration::from_secs(1));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::ShiftLeft);
+/// assert!(mock.with_inputs(|inputs| dash.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(500)).release(KeyCode::ShiftLeft);
+/// mock.with_inputs(|inputs| dash.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::ShiftLeft);
+/// assert!(!mock.with_inputs(|inputs| dash.evaluate(inputs).pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(500)).release(KeyCode::ShiftLeft);
+/// mock.with_inputs(|inputs| dash.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::ShiftLeft);
+/// assert!(mock.with_inputs(|inputs| dash.evaluate(inputs).just_pressed));
+///
+/// // A press and release within one frame still activates it.
+/// mock.next_frame().advance(Duration::from_secs(1)).release(KeyCode::ShiftLeft);
+/// mock.with_inputs(|inputs| dash.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::ShiftLeft).release(KeyCode::ShiftLeft);
+/// let state = mock.with_inputs(|inputs| dash.evaluate(inputs));
+/// assert!(state.just_pressed && state.just_released);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Cooldown<T: TriggerBinding> {
+    pub binding: T,
+    pub duration: Duration,
+    activated_at: Option<Duration>,
+    active: bool,
+}
+
+impl<T: TriggerBinding> Cooldown<T> {
+    pub fn new(binding: T, duration: Duration) -> Self {
+        Self {
+            binding,
+            duration,
+            activated_at: None,
+            active: false,
+        }
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for Cooldown<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+        let now = inputs.time.elapsed();
+        let state = self.binding.evaluate(inputs);
+
+        let elapsed = self.activated_at.map(|at| now.saturating_sub(at));
+        let ready = elapsed.is_none_or(|elapsed| elapsed >= self.duration);
+        let activated = state.just_pressed && ready;
+        if activated {
+            self.activated_at = Some(now);
+            self.active = true;
+        }
+        self.active &= state.pressed;
+
+        let elapsed = self.activated_at.map(|at| now.saturating_sub(at));
+        let progress = elapsed.map_or(1.0, |elapsed| fraction(elapsed, self.duration));
+        TriggerState::transition(previous, self.active)
+            .with_tap(activated && !self.active)
+            .with_progress(progress)
+    }
+
+    fn reset(&mut self) {
+        // The cooldown keeps running, so it can't be skipped by switching contexts.
+        self.active = false;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("cooldown", vec![self.binding.describe()])
+            .with_parameter("duration", self.duration.as_secs_f32())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Cooldown {
+            binding: Box::new(self.binding.to_data()?),
+            duration: self.duration,
+        })
+    }
+}
+
+/// A modifier that ignores changes of the binding for the given duration after its state last changed, e.g. for a
+/// worn-out button that bounces. The first press and release go through immediately, so it doesn't add latency. A
+/// change that happens during the duration is applied once it's over.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+/// # use std::time::Duration;
+/// let mut fire = MouseButton::Left.debounce(Duration::from_millis(50));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(MouseButton::Left);
+/// assert!(mock.with_inputs(|inputs| fire.evaluate(inputs).just_pressed));
+///
+/// // The button bounces, but the modifier stays pressed.
+/// mock.next_frame().advance(Duration::from_millis(10)).release(MouseButton::Left);
+/// assert!(mock.with_inputs(|inputs| fire.evaluate(inputs).pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(50));
+/// assert!(mock.with_inputs(|inputs| fire.evaluate(inputs).just_released));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Debounce<T: TriggerBinding> {
+    pub binding: T,
+    pub duration: Duration,
+    changed_at: Option<Duration>,
+    active: bool,
+}
+
+impl<T: TriggerBinding> Debounce<T> {
+    pub fn new(binding: T, duration: Duration) -> Self {
+        Self {
+            binding,
+            duration,
+            changed_at: None,
+            active: false,
+        }
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for Debounce<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+        let now = inputs.time.elapsed();
+        let state = self.binding.evaluate(inputs);
+
+        let settled = self
+            .changed_at
+            .is_none_or(|at| now.saturating_sub(at) >= self.duration);
+        if settled && state.pressed != self.active {
+            self.changed_at = Some(now);
+            self.active = state.pressed;
+        }
+
+        TriggerState::transition(previous, self.active)
+    }
+
+    fn reset(&mut self) {
+        self.changed_at = None;
+        self.active = false;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("debounce", vec![self.binding.describe()])
+            .with_parameter("duration", self.duration.as_secs_f32())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Debounce {
+            binding: Box::new(self.binding.to_data()?),
+            duration: self.duration,
+        })
+    }
+}
+
+/// A modifier that is `just_pressed` when the binding is pressed, and again every `interval` after it has been held for
+/// `initial_delay`, e.g. to keep moving through a menu while a direction is held. It stays pressed while the binding is
+/// held, so the repeats are only visible as `just_pressed`.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{TriggerBinding, TriggerBindingBuilder, testing::MockInputs};
+/// # use std::time::Duration;
+/// let mut down = KeyCode::ArrowDown.repeat(Duration::from_millis(400), Duration::from_millis(100));
+/// let mut mock = MockInputs::new();
+///
+/// mock.press(KeyCode::ArrowDown);
+/// assert!(mock.with_inputs(|inputs| down.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(300));
+/// assert!(!mock.with_inputs(|inputs| down.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(100));
+/// assert!(mock.with_inputs(|inputs| down.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(50));
+/// assert!(!mock.with_inputs(|inputs| down.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame().advance(Duration::from_millis(50));
+/// assert!(mock.with_inputs(|inputs| down.evaluate(inputs).just_pressed));
+///
+/// // A press and release within one frame is a single step.
+/// mock.next_frame().release(KeyCode::ArrowDown);
+/// mock.with_inputs(|inputs| down.evaluate(inputs));
+/// mock.next_frame().press(KeyCode::ArrowDown).release(KeyCode::ArrowDown);
+/// let state = mock.with_inputs(|inputs| down.evaluate(inputs));
+/// assert!(state.just_pressed && state.just_released);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Repeat<T: TriggerBinding> {
+    pub binding: T,
+    pub initial_delay: Duration,
+    pub interval: Duration,
+    next_repeat: Option<Duration>,
+}
+
+impl<T: TriggerBinding> Repeat<T> {
+    pub fn new(binding: T, initial_delay: Duration, interval: Duration) -> Self {
+        Self {
+            binding,
+            initial_delay,
+            interval,
+            next_repeat: None,
+        }
+    }
+}
+
+impl<T: TriggerBinding + Clone> TriggerBinding for Repeat<T> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let now = inputs.time.elapsed();
+        let state = self.binding.evaluate(inputs);
+
+        let mut repeated = false;
+        self.next_repeat = match self.next_repeat {
+            _ if !state.pressed => None,
+            None => Some(now + self.initial_delay),
+            Some(at) if at <= now => {
+                repeated = true;
+                // Skip the repeats that were missed, e.g. after a long frame.
+                let next = at + self.interval;
+                Some(if next > now {
+                    next
+                } else {
+                    now + self.interval
+                })
+            }
+            next => next,
+        };
+
+        TriggerState {
+            just_pressed: state.just_pressed || repeated,
+            progress: None,
+            ..state
+        }
+    }
+
+    fn reset(&mut self) {
+        self.next_repeat = None;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("repeat", vec![self.binding.describe()])
+            .with_parameter("initial delay", self.initial_delay.as_secs_f32())
+            .with_parameter("interval", self.interval.as_secs_f32())
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Trigger(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::Repeat {
+            binding: Box::new(self.binding.to_data()?),
+            initial_delay: self.initial_delay,
+            interval: self.interval,
+        })
+    }
+}
+
+/// A trigger binding that is pressed when an axis binding reaches `press_at`, and released when it falls back below
+/// `release_at`, e.g. pulling an analog trigger to fire. Keeping `release_at` below `press_at` prevents the trigger from
+/// chattering when the value hovers around the threshold. Negative thresholds work the same way for values below zero.
+///
+/// Reports how far the value is towards `press_at` as its progress.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use press_here::{AxisBindingBuilder, TriggerBinding, testing::MockInputs};
+/// let mut fire = GamepadAxis::RightZ.threshold(0.6, 0.4);
+/// let mut mock = MockInputs::new();
+///
+/// mock.set_axis(GamepadAxis::RightZ, 0.7);
+/// assert!(mock.with_inputs(|inputs| fire.evaluate(inputs).just_pressed));
+///
+/// mock.next_frame().set_axis(GamepadAxis::RightZ, 0.5);
+/// assert!(mock.with_inputs(|inputs| fire.evaluate(inputs).pressed));
+///
+/// mock.next_frame().set_axis(GamepadAxis::RightZ, 0.3);
+/// assert!(mock.with_inputs(|inputs| fire.evaluate(inputs).just_released));
+/// ```
+#[derive(Clone, Copy)]
+pub struct AxisThreshold<A: AxisBinding> {
+    pub binding: A,
+    pub press_at: f32,
+    pub release_at: f32,
+    active: bool,
+}
+
+impl<A: AxisBinding> AxisThreshold<A> {
+    pub fn new(binding: A, press_at: f32, release_at: f32) -> Self {
+        Self {
+            binding,
+            press_at,
+            release_at,
+            active: false,
+        }
+    }
+}
+
+impl<A: AxisBinding + Clone> TriggerBinding for AxisThreshold<A> {
+    fn evaluate(&mut self, inputs: &Inputs) -> TriggerState {
+        let previous = self.active;
+
+        // Flip the value for negative thresholds, so that both directions can be compared the same way.
+        let value = self.binding.value(inputs).unwrap_or(0.0) * self.press_at.signum();
+        self.active = if self.active {
+            value >= self.release_at.abs()
+        } else {
+            value >= self.press_at.abs()
+        };
+
+        let progress = if self.press_at == 0.0 {
+            1.0
+        } else {
+            (value / self.press_at.abs()).clamp(0.0, 1.0)
+        };
+        TriggerState::transition(previous, self.active).with_progress(progress)
+    }
+
+    fn reset(&mut self) {
+        self.active = false;
+        self.binding.reset();
+    }
+
+    fn clone_trigger(&self) -> Box<dyn TriggerBinding> {
+        Box::new(self.clone())
+    }
+
+    fn describe(&self) -> BindingDescription {
+        BindingDescription::modifier("threshold", vec![self.binding.describe()])
+            .with_parameter("press at", self.press_at)
+            .with_parameter("release at", self.release_at)
+    }
+
+    fn children_mut(&mut self) -> Vec<BindingMut<'_>> {
+        vec![BindingMut::Axis(&mut self.binding)]
+    }
+
+    fn to_data(&self) -> Option<TriggerBindingData> {
+        Some(TriggerBindingData::AxisThreshold {
+            binding: Box::new(self.binding.to_data()?),
+            press_at: self.press_at,
+            release_at: self.release_at,
+        })
+    }
 }
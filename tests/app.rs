@@ -0,0 +1,256 @@
+//! Actions driven through an [App] with [MockAppExt]: contexts, input consumption, latching, clocks, and capture.
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+use press_here::{
+    AppExt, Axis, AxisBindingBuilder, BindingCapture, BindingCaptured, CaptureOutcome,
+    CaptureRequest, InputClock, InputContext, InputContexts, Pair, PressHerePlugin,
+    PressHereSystems, Trigger, TriggerBindingBuilder, testing::MockAppExt,
+};
+use std::time::Duration;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+struct Jump;
+struct Confirm;
+struct Walk;
+struct Gameplay;
+struct Menu;
+
+fn contexts(app: &mut App) -> Mut<'_, InputContexts> {
+    app.world_mut().resource_mut::<InputContexts>()
+}
+
+fn jump(app: &App) -> &Trigger<Jump> {
+    app.world().resource::<Trigger<Jump>>()
+}
+
+#[test]
+fn held_trigger_is_pressed_when_context_is_reactivated() {
+    let mut app = App::new();
+    app.init_mock_inputs()
+        .add_trigger::<Jump>(KeyCode::Space)
+        .set_action_context::<Jump>(InputContext::of::<Gameplay>());
+    contexts(&mut app).push(InputContext::of::<Gameplay>());
+
+    app.press(KeyCode::Space).update();
+    assert!(jump(&app).just_pressed());
+
+    contexts(&mut app).push(InputContext::of::<Menu>());
+    app.update();
+    assert!(jump(&app).just_released());
+
+    contexts(&mut app).pop();
+    app.update();
+    assert!(jump(&app).just_pressed());
+}
+
+#[test]
+fn deactivated_trigger_forgets_its_last_press() {
+    let mut app = App::new();
+    app.init_mock_inputs()
+        .add_trigger::<Jump>(KeyCode::Space)
+        .set_action_context::<Jump>(InputContext::of::<Gameplay>());
+    contexts(&mut app).push(InputContext::of::<Gameplay>());
+
+    app.press(KeyCode::Space).update();
+    app.release(KeyCode::Space).update();
+    assert!(jump(&app).pressed_within(Duration::from_secs(1)));
+
+    contexts(&mut app).push(InputContext::of::<Menu>());
+    app.update();
+    contexts(&mut app).pop();
+    app.update();
+    assert!(!jump(&app).pressed_within(Duration::from_secs(1)));
+}
+
+#[test]
+fn smoothed_axis_starts_fresh_when_context_is_reactivated() {
+    let mut app = App::new();
+    app.init_mock_inputs()
+        .add_axis::<Walk>(Pair(KeyCode::KeyA, KeyCode::KeyD).smooth(0.1))
+        .set_action_context::<Walk>(InputContext::of::<Gameplay>());
+    contexts(&mut app).push(InputContext::of::<Gameplay>());
+
+    app.press(KeyCode::KeyD);
+    for _ in 0..20 {
+        app.advance(FRAME).update();
+    }
+    assert!(app.world().resource::<Axis<Walk>>().value() > 0.9);
+
+    contexts(&mut app).push(InputContext::of::<Menu>());
+    app.release(KeyCode::KeyD).advance(FRAME).update();
+    assert_eq!(app.world().resource::<Axis<Walk>>().value(), 0.0);
+
+    // Without a reset, the axis would decay from the value it had before the menu opened.
+    contexts(&mut app).pop();
+    app.advance(FRAME).update();
+    assert_eq!(app.world().resource::<Axis<Walk>>().value(), 0.0);
+}
+
+/// Whether Jump and Confirm were pressed, as seen by a system ordered after [PressHereSystems::Consume].
+#[derive(Resource, Default)]
+struct Seen(Vec<(bool, bool)>);
+
+fn record(mut seen: ResMut<Seen>, jump: Res<Trigger<Jump>>, confirm: Res<Trigger<Confirm>>) {
+    seen.0.push((jump.pressed(), confirm.pressed()));
+}
+
+#[test]
+fn systems_after_consume_set_see_suppressed_actions() {
+    let mut app = App::new();
+    app.init_mock_inputs()
+        .add_plugins(PressHerePlugin::default().with_input_consumption())
+        .add_trigger::<Jump>(KeyCode::Space)
+        .add_trigger::<Confirm>(KeyCode::Space)
+        .set_action_priority::<Confirm>(1)
+        .init_resource::<Seen>()
+        .add_systems(PreUpdate, record.after(PressHereSystems::Consume));
+
+    app.press(KeyCode::Space).update();
+    app.update();
+    assert_eq!(
+        app.world().resource::<Seen>().0,
+        [(false, true), (false, true)]
+    );
+
+    // The suppressed press isn't buffered either.
+    assert!(!jump(&app).pressed_within(Duration::from_secs(1)));
+}
+
+#[test]
+fn consumption_sees_latched_presses() {
+    let mut app = App::new();
+    app.init_mock_inputs()
+        .add_plugins(
+            PressHerePlugin::default()
+                .with_schedule(Step)
+                .with_input_latching()
+                .with_input_consumption(),
+        )
+        .add_trigger::<Jump>(KeyCode::Space)
+        .add_trigger::<Confirm>(KeyCode::Space)
+        .set_action_priority::<Confirm>(1);
+
+    app.press(KeyCode::Space).release(KeyCode::Space).update();
+    app.update();
+    app.world_mut().run_schedule(Step);
+
+    let confirm = app.world().resource::<Trigger<Confirm>>();
+    assert!(confirm.just_pressed() && confirm.just_released());
+    assert!(!jump(&app).just_pressed());
+}
+
+/// A schedule that is run by hand, standing in for one that doesn't run every frame, like `FixedPreUpdate`.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct Step;
+
+#[test]
+fn latched_press_is_seen_exactly_once() {
+    let mut app = App::new();
+    app.init_mock_inputs()
+        .add_plugins(
+            PressHerePlugin::default()
+                .with_schedule(Step)
+                .with_input_latching(),
+        )
+        .add_trigger::<Jump>(KeyCode::Space);
+
+    // Tapped on a frame without a step.
+    app.press(KeyCode::Space).release(KeyCode::Space).update();
+    app.update();
+    app.world_mut().run_schedule(Step);
+    assert!(jump(&app).just_pressed() && !jump(&app).pressed());
+
+    app.world_mut().run_schedule(Step);
+    assert!(!jump(&app).just_pressed());
+
+    // Held on a frame with two steps.
+    app.press(KeyCode::Space).update();
+    app.world_mut().run_schedule(Step);
+    assert!(jump(&app).just_pressed());
+    app.world_mut().run_schedule(Step);
+    assert!(jump(&app).pressed() && !jump(&app).just_pressed());
+}
+
+#[derive(Default)]
+struct Replay;
+
+#[test]
+fn actions_read_the_configured_clock() {
+    let mut app = App::new();
+    app.init_mock_inputs()
+        .add_plugins(PressHerePlugin::default().with_clock(InputClock::custom::<Replay>()))
+        .init_resource::<Time<Replay>>()
+        .add_input_clock::<Replay>()
+        .add_trigger::<Jump>(KeyCode::Space.hold(Duration::from_millis(100)))
+        .add_trigger::<Confirm>(KeyCode::Space.hold(Duration::from_millis(100)));
+    app.world_mut()
+        .resource_mut::<Trigger<Confirm>>()
+        .set_clock(Some(InputClock::Real));
+
+    app.press(KeyCode::Space).update();
+    app.advance(Duration::from_millis(200)).update();
+    assert!(!jump(&app).pressed());
+    assert!(app.world().resource::<Trigger<Confirm>>().pressed());
+
+    app.world_mut()
+        .resource_mut::<Time<Replay>>()
+        .advance_by(Duration::from_millis(200));
+    app.update();
+    assert!(jump(&app).pressed());
+}
+
+fn captured(app: &mut App) -> Vec<BindingCaptured> {
+    app.world_mut()
+        .resource_mut::<Messages<BindingCaptured>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn capture_rebinds_trigger() {
+    let mut app = App::new();
+    app.init_mock_inputs().add_trigger::<Jump>(KeyCode::Space);
+
+    // Keys held when the capture starts are ignored until they're pressed again.
+    app.press(KeyCode::KeyK).update();
+    app.world_mut()
+        .resource_mut::<BindingCapture>()
+        .start(CaptureRequest::trigger::<Jump>(0));
+    app.update();
+    app.update();
+    assert!(captured(&mut app).is_empty());
+
+    app.press(KeyCode::KeyJ).update();
+    let messages = captured(&mut app);
+    assert_eq!(messages.len(), 1);
+    assert!(!app.world().resource::<BindingCapture>().is_capturing());
+
+    let mut trigger = app.world_mut().resource_mut::<Trigger<Jump>>();
+    assert!(messages[0].apply_to_trigger(&mut trigger));
+    assert_eq!(trigger.binding().describe().to_string(), "J");
+}
+
+#[test]
+fn capture_can_be_cancelled_or_time_out() {
+    let mut app = App::new();
+    app.init_mock_inputs().add_trigger::<Jump>(KeyCode::Space);
+
+    app.world_mut()
+        .resource_mut::<BindingCapture>()
+        .start(CaptureRequest::trigger::<Jump>(0));
+    app.update();
+    app.press(KeyCode::Escape).update();
+    let messages = captured(&mut app);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].outcome, CaptureOutcome::Cancelled);
+
+    app.world_mut()
+        .resource_mut::<BindingCapture>()
+        .start(CaptureRequest::trigger::<Jump>(0).with_timeout(Duration::from_secs(1)));
+    app.update();
+    app.advance(Duration::from_secs(2)).update();
+    let messages = captured(&mut app);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].outcome, CaptureOutcome::TimedOut);
+}
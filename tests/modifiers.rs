@@ -0,0 +1,110 @@
+//! Trigger modifiers evaluated against [MockInputs], in particular inputs that are pressed and released within one frame.
+
+use bevy::prelude::*;
+use press_here::{
+    Chord, GamepadPolicy, ModifierKey, OnGamepad, TriggerBinding, TriggerBindingBuilder,
+    TriggerState, testing::MockInputs,
+};
+use std::time::Duration;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn evaluate(binding: &mut impl TriggerBinding, mock: &MockInputs) -> TriggerState {
+    mock.with_inputs(|inputs| binding.evaluate(inputs))
+}
+
+/// Presses and releases the key within the next frame.
+fn tap(mock: &mut MockInputs, key: KeyCode) {
+    mock.next_frame().advance(FRAME).press(key).release(key);
+}
+
+#[test]
+fn cooldown_reports_same_frame_tap() {
+    let mut fire = KeyCode::Space.cooldown(Duration::from_millis(500));
+    let mut mock = MockInputs::new();
+
+    tap(&mut mock, KeyCode::Space);
+    let state = evaluate(&mut fire, &mock);
+    assert!(state.just_pressed && state.just_released && !state.pressed);
+
+    // The tap started the cooldown.
+    tap(&mut mock, KeyCode::Space);
+    let state = evaluate(&mut fire, &mock);
+    assert!(!state.just_pressed && !state.pressed);
+}
+
+#[test]
+fn repeat_reports_same_frame_tap() {
+    let mut scroll =
+        KeyCode::ArrowDown.repeat(Duration::from_millis(300), Duration::from_millis(100));
+    let mut mock = MockInputs::new();
+
+    tap(&mut mock, KeyCode::ArrowDown);
+    let state = evaluate(&mut scroll, &mock);
+    assert!(state.just_pressed && state.just_released && !state.pressed);
+
+    mock.next_frame().advance(Duration::from_millis(500));
+    assert_eq!(evaluate(&mut scroll, &mock), TriggerState::default());
+}
+
+#[test]
+fn multi_tap_reports_same_frame_final_tap() {
+    let mut dash = KeyCode::ShiftLeft.double_tap(Duration::from_millis(300));
+    let mut mock = MockInputs::new();
+
+    mock.press(KeyCode::ShiftLeft);
+    assert!(!evaluate(&mut dash, &mock).just_pressed);
+    mock.next_frame().advance(FRAME).release(KeyCode::ShiftLeft);
+    assert!(!evaluate(&mut dash, &mock).just_pressed);
+
+    tap(&mut mock, KeyCode::ShiftLeft);
+    let state = evaluate(&mut dash, &mock);
+    assert!(state.just_pressed && state.just_released && !state.pressed);
+
+    // A third tap starts a new sequence.
+    tap(&mut mock, KeyCode::ShiftLeft);
+    assert!(!evaluate(&mut dash, &mock).just_pressed);
+}
+
+#[test]
+fn chord_reports_same_frame_tap() {
+    let mut save = Chord::new([ModifierKey::Control], KeyCode::KeyS);
+    let mut mock = MockInputs::new();
+
+    mock.press(KeyCode::ControlLeft);
+    evaluate(&mut save, &mock);
+
+    tap(&mut mock, KeyCode::KeyS);
+    let state = evaluate(&mut save, &mock);
+    assert!(state.just_pressed && state.just_released && !state.pressed);
+
+    // Without the modifier, the tap doesn't count.
+    mock.release(KeyCode::ControlLeft);
+    tap(&mut mock, KeyCode::KeyS);
+    assert!(!evaluate(&mut save, &mock).just_pressed);
+}
+
+#[test]
+fn gamepad_policy_filters_gamepads() {
+    let (first, second) = (Entity::from_bits(1), Entity::from_bits(2));
+    let mut mock = MockInputs::new();
+    mock.gamepad_mut(first);
+    mock.gamepad_mut(second)
+        .digital_mut()
+        .press(GamepadButton::South);
+
+    let mut any = OnGamepad(GamepadButton::South, GamepadPolicy::Any);
+    let mut first_only = OnGamepad(GamepadButton::South, GamepadPolicy::Gamepad(first));
+    let mut second_only = OnGamepad(GamepadButton::South, GamepadPolicy::Gamepad(second));
+    let mut last_used = OnGamepad(GamepadButton::South, GamepadPolicy::LastUsed);
+
+    assert!(evaluate(&mut any, &mock).pressed);
+    assert!(!evaluate(&mut first_only, &mock).pressed);
+    assert!(evaluate(&mut second_only, &mock).pressed);
+    assert!(!evaluate(&mut last_used, &mock).pressed);
+
+    mock.set_last_used_gamepad(Some(second));
+    assert!(evaluate(&mut last_used, &mock).pressed);
+    mock.set_last_used_gamepad(Some(first));
+    assert!(!evaluate(&mut last_used, &mock).pressed);
+}